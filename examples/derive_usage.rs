@@ -0,0 +1,70 @@
+//! Example showing `#[derive(Validate)]`
+//!
+//! Run with `cargo run --example derive_usage --features derive`
+
+use validator_rs::Validate;
+
+#[derive(Debug, Validate)]
+struct UserRegistration {
+    #[validate(email)]
+    email: String,
+    #[validate(phone(locale = "en-US", strict))]
+    phone: String,
+    #[validate(url(https))]
+    website: String,
+    #[validate(credit_card)]
+    credit_card: String,
+    #[validate(length(min = 8, max = 128))]
+    password: String,
+    #[validate(range(min = 18, max = 120))]
+    age: u32,
+    #[validate(currency(symbol = "€", thousands_separator = '.', decimal_separator = ','))]
+    deposit: Option<String>,
+}
+
+fn main() {
+    println!("=== Derive Validation Examples ===\n");
+
+    let users = vec![
+        (
+            "valid",
+            UserRegistration {
+                email: "user@example.com".to_string(),
+                phone: "+14155552671".to_string(),
+                website: "https://example.com".to_string(),
+                credit_card: "4532015112830366".to_string(),
+                password: "correct horse".to_string(),
+                age: 30,
+                deposit: Some("€1.234,56".to_string()),
+            },
+        ),
+        (
+            "invalid",
+            UserRegistration {
+                email: "invalid-email".to_string(),
+                phone: "4155552671".to_string(),
+                website: "http://example.com".to_string(),
+                credit_card: "1234567890123456".to_string(),
+                password: "short".to_string(),
+                age: 17,
+                deposit: Some("$1,234.56".to_string()),
+            },
+        ),
+    ];
+
+    for (label, user) in users {
+        println!("--- {} user ---", label);
+        match user.validate() {
+            Ok(()) => println!("✓ User registration is valid!"),
+            Err(errors) => {
+                println!("✗ {} validation errors:", errors.len());
+                for error in &errors {
+                    println!("  - {}", error);
+                }
+            }
+        }
+        println!();
+    }
+
+    println!("=== End of Derive Examples ===");
+}
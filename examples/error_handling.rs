@@ -2,6 +2,9 @@
 
 use validator_rs::{ValidationError, ValidationResult};
 use validator_rs::*;
+use validator_rs::email::EmailValidator;
+use validator_rs::numeric::RangeValidator;
+use validator_rs::string::LengthValidator;
 use validator_rs::url::is_valid_https_url;
 
 /// Example struct for user registration
@@ -83,10 +86,15 @@ fn validate_password(password: &str) -> Result<(), Vec<String>> {
 
 /// Validates age with range checking
 fn validate_age(age: i32) -> ValidationResult {
-    if !numeric::is_in_range(age, 18, 120) {
-        return Err(ValidationError::new("age", "Age must be between 18 and 120"));
-    }
-    Ok(())
+    RangeValidator::new().min(18).max(120).validate(&age, "age")
+}
+
+/// Validates an optional contact email composed from reusable validators
+fn validate_contact_email(email: &Option<String>) -> ValidationResult {
+    EmailValidator::new()
+        .and(LengthValidator::new().max(64))
+        .optional()
+        .validate(email, "contact_email")
 }
 
 fn main() {
@@ -166,6 +174,22 @@ fn main() {
     }
     println!();
 
+    // Example 5: Composed validators
+    println!("--- Composed Validators ---");
+    let contact_emails = vec![
+        None,
+        Some("user@example.com".to_string()),
+        Some("invalid-email".to_string()),
+    ];
+    for email in contact_emails {
+        print!("Contact email {:?}: ", email);
+        match validate_contact_email(&email) {
+            Ok(()) => println!("✓ Valid"),
+            Err(error) => println!("✗ {}", error),
+        }
+    }
+    println!();
+
     println!("=== End of Advanced Examples ===");
 }
 
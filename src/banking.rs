@@ -0,0 +1,949 @@
+//! Bank identifier validation
+//!
+//! Parses BIC/SWIFT codes, US ABA routing numbers, UK sort codes and account
+//! numbers (with VocaLink modulus checking), Indian IFSC codes and Canadian
+//! transit numbers into structured values. For IBANs see [`crate::iban`].
+
+use crate::checksum::is_valid_weighted_mod10;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// ISO 3166-1 alpha-2 country codes
+const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Returns `true` for an ISO 3166-1 alpha-2 code, or `XK` (Kosovo) as used by SWIFT
+fn is_country_code(code: &str) -> bool {
+    code == "XK" || COUNTRY_CODES.binary_search(&code).is_ok()
+}
+
+/// Reason a bank identifier failed to parse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BankingError {
+    /// The identifier has the wrong number of characters
+    InvalidLength(usize),
+    /// The identifier contains a character not allowed at its position
+    InvalidCharacter(char),
+    /// The identifier does not have the expected structure
+    InvalidFormat,
+    /// The country code is not an ISO 3166-1 country
+    UnknownCountry(String),
+    /// The check digit or modulus check does not match
+    InvalidChecksum,
+}
+
+impl fmt::Display for BankingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankingError::InvalidLength(length) => {
+                write!(f, "bank identifier has an invalid length of {}", length)
+            }
+            BankingError::InvalidCharacter(c) => {
+                write!(f, "bank identifier contains invalid character '{}'", c)
+            }
+            BankingError::InvalidFormat => f.write_str("bank identifier has an invalid format"),
+            BankingError::UnknownCountry(country) => {
+                write!(f, "unknown country code '{}'", country)
+            }
+            BankingError::InvalidChecksum => f.write_str("bank identifier has an invalid checksum"),
+        }
+    }
+}
+
+impl std::error::Error for BankingError {}
+
+/// Checks that every character of `value` satisfies `allowed`
+fn check_chars(value: &str, allowed: impl Fn(char) -> bool) -> Result<(), BankingError> {
+    match value.chars().find(|&c| !allowed(c)) {
+        Some(c) => Err(BankingError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+fn is_upper_alphanumeric(c: char) -> bool {
+    c.is_ascii_uppercase() || c.is_ascii_digit()
+}
+
+/// A BIC (Business Identifier Code, ISO 9362), also known as a SWIFT code
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::Bic;
+///
+/// let bic = Bic::parse("DEUTDEFF500").unwrap();
+/// assert_eq!(bic.institution(), "DEUT");
+/// assert_eq!(bic.country(), "DE");
+/// assert_eq!(bic.location(), "FF");
+/// assert_eq!(bic.branch(), Some("500"));
+/// assert!(!bic.is_primary_office());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bic {
+    value: String,
+}
+
+impl Bic {
+    /// Parses an 8 or 11 character BIC, accepting lowercase letters
+    ///
+    /// The institution code, location and branch are alphanumeric (ISO
+    /// 9362:2014), and the country must be an ISO 3166-1 alpha-2 code.
+    pub fn parse(value: &str) -> Result<Self, BankingError> {
+        let bic = value.to_ascii_uppercase();
+        if bic.len() != 8 && bic.len() != 11 {
+            return Err(BankingError::InvalidLength(value.chars().count()));
+        }
+        check_chars(&bic, is_upper_alphanumeric)?;
+
+        let country = &bic[4..6];
+        check_chars(country, |c| c.is_ascii_uppercase())?;
+        if !is_country_code(country) {
+            return Err(BankingError::UnknownCountry(country.to_string()));
+        }
+
+        Ok(Self { value: bic })
+    }
+
+    /// Returns the BIC in uppercase
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the four-character institution (bank) code
+    pub fn institution(&self) -> &str {
+        &self.value[..4]
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 country code
+    pub fn country(&self) -> &str {
+        &self.value[4..6]
+    }
+
+    /// Returns the two-character location code
+    pub fn location(&self) -> &str {
+        &self.value[6..8]
+    }
+
+    /// Returns the three-character branch code of an 11-character BIC
+    pub fn branch(&self) -> Option<&str> {
+        self.value.get(8..11)
+    }
+
+    /// Returns `true` for the primary office (no branch code, or `XXX`)
+    pub fn is_primary_office(&self) -> bool {
+        matches!(self.branch(), None | Some("XXX"))
+    }
+
+    /// Returns `true` for a test and training BIC (location ending in `0`)
+    pub fn is_test(&self) -> bool {
+        self.value.as_bytes()[7] == b'0'
+    }
+}
+
+impl fmt::Display for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl std::str::FromStr for Bic {
+    type Err = BankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates a BIC/SWIFT code
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::is_valid_bic;
+///
+/// assert!(is_valid_bic("NWBKGB2L"));
+/// assert!(is_valid_bic("DEUTDEFF500"));
+/// assert!(!is_valid_bic("DEUTQQFF")); // unknown country
+/// assert!(!is_valid_bic("DEUTDEFF5"));
+/// ```
+pub fn is_valid_bic(value: &str) -> bool {
+    Bic::parse(value).is_ok()
+}
+
+/// A US ABA routing transit number
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::AbaRoutingNumber;
+///
+/// let routing = AbaRoutingNumber::parse("021000021").unwrap();
+/// assert_eq!(routing.federal_reserve_symbol(), "0210");
+/// assert_eq!(routing.institution_identifier(), "0002");
+/// assert_eq!(routing.check_digit(), '1');
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbaRoutingNumber {
+    value: String,
+}
+
+impl AbaRoutingNumber {
+    /// Parses a nine-digit routing number
+    ///
+    /// The first two digits must be a valid Federal Reserve prefix (00–12,
+    /// 21–32, 61–72 or 80) and the 3-7-1 weighted checksum must be a
+    /// multiple of 10.
+    pub fn parse(value: &str) -> Result<Self, BankingError> {
+        check_chars(value, |c| c.is_ascii_digit())?;
+        if value.len() != 9 {
+            return Err(BankingError::InvalidLength(value.len()));
+        }
+
+        let prefix: u32 = value[..2]
+            .parse()
+            .map_err(|_| BankingError::InvalidFormat)?;
+        if !matches!(prefix, 0..=12 | 21..=32 | 61..=72 | 80) {
+            return Err(BankingError::InvalidFormat);
+        }
+        if !is_valid_weighted_mod10(value, &[7, 3, 1]) {
+            return Err(BankingError::InvalidChecksum);
+        }
+
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    /// Returns the routing number
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the Federal Reserve routing symbol (first four digits)
+    pub fn federal_reserve_symbol(&self) -> &str {
+        &self.value[..4]
+    }
+
+    /// Returns the ABA institution identifier (digits five to eight)
+    pub fn institution_identifier(&self) -> &str {
+        &self.value[4..8]
+    }
+
+    /// Returns the check digit
+    pub fn check_digit(&self) -> char {
+        char::from(self.value.as_bytes()[8])
+    }
+}
+
+impl fmt::Display for AbaRoutingNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl std::str::FromStr for AbaRoutingNumber {
+    type Err = BankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates a US ABA routing number
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::is_valid_aba_routing;
+///
+/// assert!(is_valid_aba_routing("011000015"));
+/// assert!(!is_valid_aba_routing("011000016"));
+/// ```
+pub fn is_valid_aba_routing(value: &str) -> bool {
+    AbaRoutingNumber::parse(value).is_ok()
+}
+
+/// A UK bank sort code
+///
+/// Displays as `12-34-56`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortCode {
+    value: String,
+}
+
+impl SortCode {
+    /// Parses a sort code as `123456`, `12-34-56` or `12 34 56`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validator_rs::banking::SortCode;
+    ///
+    /// let sort_code = SortCode::parse("08 99 99").unwrap();
+    /// assert_eq!(sort_code.as_str(), "089999");
+    /// assert_eq!(sort_code.to_string(), "08-99-99");
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, BankingError> {
+        let digits = match value.len() {
+            6 => value.to_string(),
+            8 => {
+                let separator = value.as_bytes()[2];
+                let grouped = matches!(separator, b'-' | b' ') && value.as_bytes()[5] == separator;
+                if !grouped {
+                    return Err(BankingError::InvalidFormat);
+                }
+                value.split(char::from(separator)).collect()
+            }
+            _ => return Err(BankingError::InvalidLength(value.chars().count())),
+        };
+        check_chars(&digits, |c| c.is_ascii_digit())?;
+        if digits.len() != 6 {
+            return Err(BankingError::InvalidFormat);
+        }
+        Ok(Self { value: digits })
+    }
+
+    /// Returns the six digits of the sort code
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for SortCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            &self.value[..2],
+            &self.value[2..4],
+            &self.value[4..]
+        )
+    }
+}
+
+impl std::str::FromStr for SortCode {
+    type Err = BankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates a UK sort code
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::is_valid_sort_code;
+///
+/// assert!(is_valid_sort_code("40-47-84"));
+/// assert!(!is_valid_sort_code("40-47-8"));
+/// ```
+pub fn is_valid_sort_code(value: &str) -> bool {
+    SortCode::parse(value).is_ok()
+}
+
+/// Outcome of [`UkModulusRules::check`] for an accepted account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModulusStatus {
+    /// Every rule for the sort code passed
+    Passed,
+    /// No rule covers the sort code, so the account is valid unchecked
+    NoRule,
+    /// A rule for the sort code carries this VocaLink exception code, which
+    /// is not supported, so the account was not checked
+    UnsupportedException(u8),
+}
+
+/// A UK sort code and account number accepted by modulus checking
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UkBankAccount {
+    sort_code: SortCode,
+    account_number: String,
+    status: ModulusStatus,
+}
+
+impl UkBankAccount {
+    /// Returns the sort code
+    pub fn sort_code(&self) -> &SortCode {
+        &self.sort_code
+    }
+
+    /// Returns the eight-digit account number
+    pub fn account_number(&self) -> &str {
+        &self.account_number
+    }
+
+    /// Returns whether the account was actually checked
+    pub fn status(&self) -> ModulusStatus {
+        self.status
+    }
+}
+
+/// Modulus algorithm of a VocaLink weight table row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModulusMethod {
+    /// Weighted sum divisible by 10
+    Mod10,
+    /// Weighted sum divisible by 11
+    Mod11,
+    /// Double alternate: the digits of the products sum to a multiple of 10
+    DoubleAlternate,
+}
+
+/// A row of the VocaLink modulus weight table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulusRule {
+    /// First sort code of the range
+    pub start: u32,
+    /// Last sort code of the range
+    pub end: u32,
+    /// The check to perform
+    pub method: ModulusMethod,
+    /// Weights for the six sort code and eight account number digits
+    pub weights: [u32; 14],
+    /// VocaLink exception code, if any
+    pub exception: Option<u8>,
+}
+
+impl ModulusRule {
+    fn passes(&self, digits: &[u32; 14]) -> bool {
+        let products = digits.iter().zip(&self.weights).map(|(d, w)| d * w);
+        match self.method {
+            ModulusMethod::Mod10 => products.sum::<u32>() % 10 == 0,
+            ModulusMethod::Mod11 => products.sum::<u32>() % 11 == 0,
+            ModulusMethod::DoubleAlternate => {
+                products.map(|p| p / 10 + p % 10).sum::<u32>() % 10 == 0
+            }
+        }
+    }
+}
+
+/// UK account number modulus checking rules
+///
+/// VocaLink publishes the weight table (`valacdos.txt`) used by UK banks;
+/// load it with [`UkModulusRules::load`] or [`UkModulusRules::parse`]. Sort
+/// codes without a rule are accepted, as the VocaLink specification requires.
+///
+/// The per-row exception codes (1–14) are not supported: if any rule for a
+/// sort code carries one, the account is accepted without checking and
+/// [`UkBankAccount::status`] reports
+/// [`ModulusStatus::UnsupportedException`]. Callers that need a definite
+/// answer should treat that status as unverified.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::{ModulusStatus, UkModulusRules};
+///
+/// let rules = UkModulusRules::parse("089000 089999 MOD10 0 0 0 0 0 0 7 1 3 7 1 3 7 1").unwrap();
+/// let account = rules.check("08-99-99", "66374958").unwrap();
+/// assert_eq!(account.sort_code().as_str(), "089999");
+/// assert_eq!(account.status(), ModulusStatus::Passed);
+/// assert!(rules.check("08-99-99", "66374959").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UkModulusRules {
+    rules: Vec<ModulusRule>,
+}
+
+impl UkModulusRules {
+    /// Create an empty rule set, which accepts every account
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses rules in the VocaLink `valacdos.txt` format
+    ///
+    /// Each line holds a start and end sort code, `MOD10`, `MOD11` or
+    /// `DBLAL`, fourteen weights and an optional exception code. Blank lines
+    /// are ignored.
+    pub fn parse(text: &str) -> Result<Self, BankingError> {
+        let mut rules = Vec::new();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 17 && fields.len() != 18 {
+                return Err(BankingError::InvalidFormat);
+            }
+
+            let sort_code = |field: &str| -> Result<u32, BankingError> {
+                SortCode::parse(field)?
+                    .as_str()
+                    .parse()
+                    .map_err(|_| BankingError::InvalidFormat)
+            };
+            let method = match fields[2] {
+                "MOD10" => ModulusMethod::Mod10,
+                "MOD11" => ModulusMethod::Mod11,
+                "DBLAL" => ModulusMethod::DoubleAlternate,
+                _ => return Err(BankingError::InvalidFormat),
+            };
+            let mut weights = [0; 14];
+            for (weight, field) in weights.iter_mut().zip(&fields[3..17]) {
+                *weight = field.parse().map_err(|_| BankingError::InvalidFormat)?;
+            }
+            let exception = match fields.get(17) {
+                Some(code) => Some(code.parse().map_err(|_| BankingError::InvalidFormat)?),
+                None => None,
+            };
+
+            rules.push(ModulusRule {
+                start: sort_code(fields[0])?,
+                end: sort_code(fields[1])?,
+                method,
+                weights,
+                exception,
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    /// Loads rules from a `valacdos.txt` file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Returns the loaded rules
+    pub fn rules(&self) -> &[ModulusRule] {
+        &self.rules
+    }
+
+    /// Validates a sort code and account number
+    ///
+    /// Account numbers of six or seven digits are padded with leading zeros.
+    /// Check [`UkBankAccount::status`] to tell a passed check from an account
+    /// accepted without one.
+    pub fn check(
+        &self,
+        sort_code: &str,
+        account_number: &str,
+    ) -> Result<UkBankAccount, BankingError> {
+        let sort_code = SortCode::parse(sort_code)?;
+        check_chars(account_number, |c| c.is_ascii_digit())?;
+        if !(6..=8).contains(&account_number.len()) {
+            return Err(BankingError::InvalidLength(account_number.len()));
+        }
+        let account_number = format!("{:0>8}", account_number);
+
+        let mut digits = [0; 14];
+        for (digit, c) in digits
+            .iter_mut()
+            .zip(sort_code.as_str().chars().chain(account_number.chars()))
+        {
+            *digit = c.to_digit(10).unwrap_or(0);
+        }
+
+        let code: u32 = sort_code
+            .as_str()
+            .parse()
+            .map_err(|_| BankingError::InvalidFormat)?;
+        let rules: Vec<&ModulusRule> = self
+            .rules
+            .iter()
+            .filter(|rule| (rule.start..=rule.end).contains(&code))
+            .collect();
+        // Exceptions change which rules apply, so none are checked partially
+        let status = if let Some(exception) = rules.iter().find_map(|rule| rule.exception) {
+            ModulusStatus::UnsupportedException(exception)
+        } else if rules.is_empty() {
+            ModulusStatus::NoRule
+        } else if rules.iter().all(|rule| rule.passes(&digits)) {
+            ModulusStatus::Passed
+        } else {
+            return Err(BankingError::InvalidChecksum);
+        };
+
+        Ok(UkBankAccount {
+            sort_code,
+            account_number,
+            status,
+        })
+    }
+}
+
+/// An Indian Financial System Code
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::Ifsc;
+///
+/// let ifsc = Ifsc::parse("SBIN0001234").unwrap();
+/// assert_eq!(ifsc.bank_code(), "SBIN");
+/// assert_eq!(ifsc.branch_code(), "001234");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ifsc {
+    value: String,
+}
+
+impl Ifsc {
+    /// Parses an 11-character IFSC: four letters, `0`, and six alphanumerics
+    ///
+    /// Lowercase letters are accepted.
+    pub fn parse(value: &str) -> Result<Self, BankingError> {
+        let ifsc = value.to_ascii_uppercase();
+        if ifsc.len() != 11 {
+            return Err(BankingError::InvalidLength(value.chars().count()));
+        }
+        check_chars(&ifsc, is_upper_alphanumeric)?;
+        check_chars(&ifsc[..4], |c| c.is_ascii_uppercase())?;
+        check_chars(&ifsc[4..5], |c| c == '0')?;
+        Ok(Self { value: ifsc })
+    }
+
+    /// Returns the IFSC in uppercase
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the four-letter bank code
+    pub fn bank_code(&self) -> &str {
+        &self.value[..4]
+    }
+
+    /// Returns the six-character branch code
+    pub fn branch_code(&self) -> &str {
+        &self.value[5..]
+    }
+}
+
+impl fmt::Display for Ifsc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl std::str::FromStr for Ifsc {
+    type Err = BankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates an Indian IFSC code
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::is_valid_ifsc;
+///
+/// assert!(is_valid_ifsc("HDFC0000240"));
+/// assert!(!is_valid_ifsc("HDFC1000240"));
+/// ```
+pub fn is_valid_ifsc(value: &str) -> bool {
+    Ifsc::parse(value).is_ok()
+}
+
+/// A Canadian routing (transit) number
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::CanadianTransitNumber;
+///
+/// let micr = CanadianTransitNumber::parse("12345-003").unwrap();
+/// let eft = CanadianTransitNumber::parse("000312345").unwrap();
+/// assert_eq!(micr, eft);
+/// assert_eq!(eft.institution(), "003");
+/// assert_eq!(eft.transit(), "12345");
+/// assert_eq!(eft.micr(), "12345-003");
+/// assert_eq!(micr.electronic(), "000312345");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CanadianTransitNumber {
+    institution: String,
+    transit: String,
+}
+
+impl CanadianTransitNumber {
+    /// Parses a routing number in MICR (`TTTTT-III`) or electronic
+    /// (`0IIITTTTT`) format
+    pub fn parse(value: &str) -> Result<Self, BankingError> {
+        let (transit, institution) = match value.split_once('-') {
+            Some((transit, institution)) => (transit, institution),
+            None => {
+                check_chars(value, |c| c.is_ascii_digit())?;
+                if value.len() != 9 {
+                    return Err(BankingError::InvalidLength(value.len()));
+                }
+                if !value.starts_with('0') {
+                    return Err(BankingError::InvalidFormat);
+                }
+                (&value[4..], &value[1..4])
+            }
+        };
+        check_chars(transit, |c| c.is_ascii_digit())?;
+        check_chars(institution, |c| c.is_ascii_digit())?;
+        if transit.len() != 5 || institution.len() != 3 {
+            return Err(BankingError::InvalidFormat);
+        }
+
+        Ok(Self {
+            institution: institution.to_string(),
+            transit: transit.to_string(),
+        })
+    }
+
+    /// Returns the three-digit financial institution number
+    pub fn institution(&self) -> &str {
+        &self.institution
+    }
+
+    /// Returns the five-digit branch transit number
+    pub fn transit(&self) -> &str {
+        &self.transit
+    }
+
+    /// Returns the MICR (cheque) format `TTTTT-III`
+    pub fn micr(&self) -> String {
+        format!("{}-{}", self.transit, self.institution)
+    }
+
+    /// Returns the electronic (EFT) format `0IIITTTTT`
+    pub fn electronic(&self) -> String {
+        format!("0{}{}", self.institution, self.transit)
+    }
+}
+
+impl std::str::FromStr for CanadianTransitNumber {
+    type Err = BankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates a Canadian routing number in MICR or electronic format
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::banking::is_valid_canadian_transit;
+///
+/// assert!(is_valid_canadian_transit("00011-001"));
+/// assert!(is_valid_canadian_transit("000100011"));
+/// assert!(!is_valid_canadian_transit("100100011"));
+/// ```
+pub fn is_valid_canadian_transit(value: &str) -> bool {
+    CanadianTransitNumber::parse(value).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_country_codes_sorted() {
+        assert!(COUNTRY_CODES.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(COUNTRY_CODES.len(), 249);
+        assert!(is_country_code("XK"));
+        assert!(!is_country_code("UK"));
+    }
+
+    #[test]
+    fn test_bic() {
+        let bic: Bic = "nwbkgb2l".parse().unwrap();
+        assert_eq!(bic.as_str(), "NWBKGB2L");
+        assert_eq!(bic.branch(), None);
+        assert!(bic.is_primary_office());
+        assert!(!bic.is_test());
+        assert!(Bic::parse("NWBKGB20XXX").unwrap().is_test());
+        assert!(Bic::parse("NWBKGB2LXXX").unwrap().is_primary_office());
+        assert!(is_valid_bic("BKO1XKPR"));
+
+        assert_eq!(Bic::parse("NWBKGB2"), Err(BankingError::InvalidLength(7)));
+        assert_eq!(
+            Bic::parse("NWBKGB2L-XX"),
+            Err(BankingError::InvalidCharacter('-'))
+        );
+        assert_eq!(
+            Bic::parse("NWBK1B2L"),
+            Err(BankingError::InvalidCharacter('1'))
+        );
+        assert_eq!(
+            Bic::parse("NWBKUK2L"),
+            Err(BankingError::UnknownCountry("UK".to_string()))
+        );
+        assert!(!is_valid_bic("NWBKGBéL"));
+    }
+
+    #[test]
+    fn test_aba_routing() {
+        for routing in ["011000015", "026009593", "122105155", "800000080"] {
+            assert!(is_valid_aba_routing(routing), "{}", routing);
+        }
+        assert_eq!(
+            AbaRoutingNumber::parse("02100002"),
+            Err(BankingError::InvalidLength(8))
+        );
+        assert_eq!(
+            AbaRoutingNumber::parse("02100002a"),
+            Err(BankingError::InvalidCharacter('a'))
+        );
+        assert_eq!(
+            AbaRoutingNumber::parse("021000022"),
+            Err(BankingError::InvalidChecksum)
+        );
+        // Valid checksum, unassigned prefix 13
+        assert_eq!(
+            AbaRoutingNumber::parse("130000006"),
+            Err(BankingError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_sort_code() {
+        for value in ["404784", "40-47-84", "40 47 84"] {
+            assert_eq!(SortCode::parse(value).unwrap().as_str(), "404784");
+        }
+        assert_eq!(
+            SortCode::parse("40-47 84"),
+            Err(BankingError::InvalidFormat)
+        );
+        assert_eq!(
+            SortCode::parse("404-784"),
+            Err(BankingError::InvalidLength(7))
+        );
+        assert_eq!(
+            SortCode::parse("4047-84-"),
+            Err(BankingError::InvalidFormat)
+        );
+        assert_eq!(
+            SortCode::parse("40478a"),
+            Err(BankingError::InvalidCharacter('a'))
+        );
+    }
+
+    const RULES: &str = "
+        089000 089999 MOD10 0 0 0 0 0 0 7 1 3 7 1 3 7 1
+        107000 107999 MOD11 0 0 0 0 0 0 8 7 6 5 4 3 2 1
+        202900 202999 DBLAL 2 1 2 1 2 1 2 1 2 1 2 1 2 1
+        300000 300999 MOD11 0 0 0 0 0 0 8 7 6 5 4 3 2 1 4
+        300000 300999 DBLAL 2 1 2 1 2 1 2 1 2 1 2 1 2 1
+    ";
+
+    #[test]
+    fn test_uk_modulus_check() {
+        let rules = UkModulusRules::parse(RULES).unwrap();
+        assert_eq!(rules.rules().len(), 5);
+        assert_eq!(rules.rules()[2].method, ModulusMethod::DoubleAlternate);
+        assert_eq!(rules.rules()[3].exception, Some(4));
+
+        for (sort_code, account) in [
+            ("089999", "66374958"),
+            ("10-79-99", "88837491"),
+            ("20 29 59", "63748472"),
+        ] {
+            let account = rules.check(sort_code, account).unwrap();
+            assert_eq!(account.status(), ModulusStatus::Passed);
+        }
+        assert_eq!(
+            rules.check("107999", "88837492"),
+            Err(BankingError::InvalidChecksum)
+        );
+        assert_eq!(
+            rules.check("202959", "63748473"),
+            Err(BankingError::InvalidChecksum)
+        );
+
+        // Sort codes without a rule, or with an exception, are not checked
+        let account = rules.check("404784", "12345678").unwrap();
+        assert_eq!(account.status(), ModulusStatus::NoRule);
+        let account = rules.check("300001", "12345678").unwrap();
+        assert_eq!(account.status(), ModulusStatus::UnsupportedException(4));
+
+        let account = rules.check("404784", "345678").unwrap();
+        assert_eq!(account.account_number(), "00345678");
+        assert_eq!(
+            rules.check("404784", "12345"),
+            Err(BankingError::InvalidLength(5))
+        );
+        assert_eq!(
+            rules.check("404784", "1234567x"),
+            Err(BankingError::InvalidCharacter('x'))
+        );
+    }
+
+    #[test]
+    fn test_uk_modulus_rules_parse() {
+        assert!(UkModulusRules::new().check("089999", "66374959").is_ok());
+        assert_eq!(
+            UkModulusRules::parse("089000 089999 MOD12 0 0 0 0 0 0 7 1 3 7 1 3 7 1").unwrap_err(),
+            BankingError::InvalidFormat
+        );
+        assert_eq!(
+            UkModulusRules::parse("089000 089999 MOD10 0 0 0").unwrap_err(),
+            BankingError::InvalidFormat
+        );
+        assert_eq!(
+            UkModulusRules::parse("089000 089999 MOD10 0 0 0 0 0 0 x 1 3 7 1 3 7 1").unwrap_err(),
+            BankingError::InvalidFormat
+        );
+    }
+
+    #[test]
+    fn test_ifsc() {
+        let ifsc: Ifsc = "icic0abc123".parse().unwrap();
+        assert_eq!(ifsc.as_str(), "ICIC0ABC123");
+        assert_eq!(ifsc.branch_code(), "ABC123");
+        assert_eq!(
+            Ifsc::parse("SBIN000123"),
+            Err(BankingError::InvalidLength(10))
+        );
+        assert_eq!(
+            Ifsc::parse("SB1N0001234"),
+            Err(BankingError::InvalidCharacter('1'))
+        );
+        assert_eq!(
+            Ifsc::parse("SBINO001234"),
+            Err(BankingError::InvalidCharacter('O'))
+        );
+        assert_eq!(
+            Ifsc::parse("SBIN000123-"),
+            Err(BankingError::InvalidCharacter('-'))
+        );
+    }
+
+    #[test]
+    fn test_canadian_transit() {
+        let transit: CanadianTransitNumber = "00011-001".parse().unwrap();
+        assert_eq!(transit.electronic(), "000100011");
+        assert_eq!(
+            CanadianTransitNumber::parse("0011-001"),
+            Err(BankingError::InvalidFormat)
+        );
+        assert_eq!(
+            CanadianTransitNumber::parse("00011-0a1"),
+            Err(BankingError::InvalidCharacter('a'))
+        );
+        assert_eq!(
+            CanadianTransitNumber::parse("00010001"),
+            Err(BankingError::InvalidLength(8))
+        );
+        assert_eq!(
+            CanadianTransitNumber::parse("00010001x"),
+            Err(BankingError::InvalidCharacter('x'))
+        );
+    }
+}
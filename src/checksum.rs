@@ -0,0 +1,530 @@
+//! Check digit algorithms
+//!
+//! Validation and check digit computation for Luhn (used by payment cards
+//! and IMEIs), Luhn mod N, Verhoeff, Damm, ISO 7064 (MOD 11-2, MOD 97-10 and
+//! MOD 37-36) and weighted modulus 11/10 schemes (ISBN, GTIN and similar).
+//!
+//! Each `is_valid_*` function checks a value whose last character is the
+//! check character; each `*_check_digit` function computes the check
+//! character for a payload. Inputs with unexpected characters are invalid.
+
+/// Converts a string of ASCII digits to their values
+fn digits(value: &str) -> Option<Vec<u32>> {
+    value.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// Splits a value into its payload and check character
+fn split_check(value: &str) -> Option<(&str, char)> {
+    let check = value.chars().last()?;
+    Some((&value[..value.len() - check.len_utf8()], check))
+}
+
+fn digit_char(digit: u32) -> char {
+    char::from_digit(digit, 10).unwrap_or('0')
+}
+
+/// Validates a number with a trailing Luhn (mod 10) check digit
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::is_valid_luhn;
+///
+/// assert!(is_valid_luhn("79927398713"));
+/// assert!(is_valid_luhn("490154203237518")); // IMEI
+/// assert!(!is_valid_luhn("79927398714"));
+/// ```
+pub fn is_valid_luhn(number: &str) -> bool {
+    match split_check(number) {
+        Some((payload, check)) => luhn_check_digit(payload) == Some(check),
+        None => false,
+    }
+}
+
+/// Computes the Luhn check digit for a string of digits
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::luhn_check_digit;
+///
+/// assert_eq!(luhn_check_digit("7992739871"), Some('3'));
+/// assert_eq!(luhn_check_digit("12a"), None);
+/// ```
+pub fn luhn_check_digit(payload: &str) -> Option<char> {
+    let sum: u32 = digits(payload)?
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| {
+            if index % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    Some(digit_char((10 - sum % 10) % 10))
+}
+
+/// Validates a value with a trailing Luhn mod N check character
+///
+/// `alphabet` lists the valid characters in order; its length is N. With the
+/// alphabet `"0123456789"` this is the ordinary Luhn algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{is_valid_luhn_mod_n, luhn_mod_n_check_character};
+///
+/// let alphabet = "abcdef";
+/// assert_eq!(luhn_mod_n_check_character("abcdef", alphabet), Some('e'));
+/// assert!(is_valid_luhn_mod_n("abcdefe", alphabet));
+/// assert!(!is_valid_luhn_mod_n("abcdeff", alphabet));
+/// ```
+pub fn is_valid_luhn_mod_n(value: &str, alphabet: &str) -> bool {
+    match split_check(value) {
+        Some((payload, check)) => luhn_mod_n_check_character(payload, alphabet) == Some(check),
+        None => false,
+    }
+}
+
+/// Computes the Luhn mod N check character for a payload
+///
+/// Returns `None` if the payload contains characters outside `alphabet` or
+/// the alphabet has fewer than two characters.
+pub fn luhn_mod_n_check_character(payload: &str, alphabet: &str) -> Option<char> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    let n = alphabet.len() as u32;
+    if n < 2 {
+        return None;
+    }
+
+    let mut sum = 0;
+    for (index, c) in payload.chars().rev().enumerate() {
+        let code_point = alphabet.iter().position(|&a| a == c)? as u32;
+        let addend = if index % 2 == 0 {
+            code_point * 2
+        } else {
+            code_point
+        };
+        sum += addend / n + addend % n;
+    }
+    alphabet.get(((n - sum % n) % n) as usize).copied()
+}
+
+const VERHOEFF_MULTIPLICATION: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+const VERHOEFF_PERMUTATION: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 8, 7, 6, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const VERHOEFF_INVERSE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Runs the Verhoeff checksum over digits, the first of which has position `offset`
+fn verhoeff(digits: &[u32], offset: usize) -> u8 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |checksum, (index, &digit)| {
+            let permuted = VERHOEFF_PERMUTATION[(index + offset) % 8][digit as usize];
+            VERHOEFF_MULTIPLICATION[checksum as usize][permuted as usize]
+        })
+}
+
+/// Validates a number with a trailing Verhoeff check digit
+///
+/// The Verhoeff algorithm detects all single-digit errors and all
+/// transpositions of adjacent digits. It is used by Aadhaar numbers.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{is_valid_verhoeff, verhoeff_check_digit};
+///
+/// assert_eq!(verhoeff_check_digit("236"), Some('3'));
+/// assert!(is_valid_verhoeff("2363"));
+/// assert!(!is_valid_verhoeff("2633"));
+/// ```
+pub fn is_valid_verhoeff(number: &str) -> bool {
+    match digits(number) {
+        Some(digits) if !digits.is_empty() => verhoeff(&digits, 0) == 0,
+        _ => false,
+    }
+}
+
+/// Computes the Verhoeff check digit for a string of digits
+pub fn verhoeff_check_digit(payload: &str) -> Option<char> {
+    let checksum = verhoeff(&digits(payload)?, 1);
+    Some(digit_char(u32::from(VERHOEFF_INVERSE[checksum as usize])))
+}
+
+const DAMM_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+fn damm(digits: &[u32]) -> u8 {
+    digits.iter().fold(0, |interim, &digit| {
+        DAMM_TABLE[interim as usize][digit as usize]
+    })
+}
+
+/// Validates a number with a trailing Damm check digit
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{damm_check_digit, is_valid_damm};
+///
+/// assert_eq!(damm_check_digit("572"), Some('4'));
+/// assert!(is_valid_damm("5724"));
+/// assert!(!is_valid_damm("5274"));
+/// ```
+pub fn is_valid_damm(number: &str) -> bool {
+    match digits(number) {
+        Some(digits) if !digits.is_empty() => damm(&digits) == 0,
+        _ => false,
+    }
+}
+
+/// Computes the Damm check digit for a string of digits
+pub fn damm_check_digit(payload: &str) -> Option<char> {
+    Some(digit_char(u32::from(damm(&digits(payload)?))))
+}
+
+/// Validates a number with a trailing ISO 7064 MOD 11-2 check character
+///
+/// The check character is a digit or `X` (for 10). Used by ISNI, ORCID and
+/// Chinese resident identity numbers.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{is_valid_iso7064_mod11_2, iso7064_mod11_2_check_character};
+///
+/// assert_eq!(iso7064_mod11_2_check_character("000000021825009"), Some('7'));
+/// assert!(is_valid_iso7064_mod11_2("0000000218250097")); // ORCID
+/// assert!(is_valid_iso7064_mod11_2("11010519491231002X"));
+/// ```
+pub fn is_valid_iso7064_mod11_2(value: &str) -> bool {
+    match split_check(value) {
+        Some((payload, check)) => {
+            iso7064_mod11_2_check_character(payload) == Some(check.to_ascii_uppercase())
+        }
+        None => false,
+    }
+}
+
+/// Computes the ISO 7064 MOD 11-2 check character (`0`–`9` or `X`)
+pub fn iso7064_mod11_2_check_character(payload: &str) -> Option<char> {
+    let remainder = digits(payload)?
+        .iter()
+        .fold(0, |remainder, &digit| (remainder + digit) * 2 % 11);
+    match (12 - remainder) % 11 {
+        10 => Some('X'),
+        check => Some(digit_char(check)),
+    }
+}
+
+/// Computes the remainder modulo 97 of an alphanumeric string
+///
+/// Letters count as two-digit numbers (`A` = 10 … `Z` = 35), as in IBANs.
+/// Returns `None` for other characters.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::mod97;
+///
+/// assert_eq!(mod97("195"), Some(1));
+/// assert_eq!(mod97("A"), Some(10));
+/// assert_eq!(mod97("1A"), Some(110 % 97));
+/// ```
+pub fn mod97(value: &str) -> Option<u32> {
+    value.chars().try_fold(0, |remainder, c| {
+        let value = c.to_digit(36)?;
+        let shift = if value < 10 { 10 } else { 100 };
+        Some((remainder * shift + value) % 97)
+    })
+}
+
+/// Validates a value ending in two ISO 7064 MOD 97-10 check digits
+///
+/// The whole value, with letters converted as in [`mod97`], must leave a
+/// remainder of 1. IBANs use this scheme after moving the country code and
+/// check digits to the end.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{is_valid_iso7064_mod97_10, iso7064_mod97_10_check_digits};
+///
+/// assert_eq!(iso7064_mod97_10_check_digits("794").unwrap(), "44");
+/// assert!(is_valid_iso7064_mod97_10("79444"));
+/// assert!(!is_valid_iso7064_mod97_10("79445"));
+/// ```
+pub fn is_valid_iso7064_mod97_10(value: &str) -> bool {
+    value.len() > 2 && value.is_ascii() && mod97(value) == Some(1)
+}
+
+/// Computes the two ISO 7064 MOD 97-10 check digits for a payload
+pub fn iso7064_mod97_10_check_digits(payload: &str) -> Option<String> {
+    let remainder = mod97(payload)? * 100 % 97;
+    Some(format!("{:02}", 98 - remainder))
+}
+
+/// Validates a value with a trailing ISO 7064 MOD 37-36 check character
+///
+/// Works on the alphabet `0`–`9`, `A`–`Z` (case-insensitive).
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::{is_valid_iso7064_mod37_36, iso7064_mod37_36_check_character};
+///
+/// assert_eq!(iso7064_mod37_36_check_character("A12425GABC1234002"), Some('M'));
+/// assert!(is_valid_iso7064_mod37_36("A12425GABC1234002M"));
+/// ```
+pub fn is_valid_iso7064_mod37_36(value: &str) -> bool {
+    match split_check(value) {
+        Some((payload, check)) => {
+            iso7064_mod37_36_check_character(payload) == Some(check.to_ascii_uppercase())
+        }
+        None => false,
+    }
+}
+
+/// Computes the ISO 7064 MOD 37-36 check character (`0`–`9` or `A`–`Z`)
+pub fn iso7064_mod37_36_check_character(payload: &str) -> Option<char> {
+    const MODULUS: u32 = 36;
+    let product = payload.chars().try_fold(MODULUS, |product, c| {
+        if !c.is_ascii_alphanumeric() {
+            return None;
+        }
+        let sum = match (product + c.to_digit(36)?) % MODULUS {
+            0 => MODULUS,
+            sum => sum,
+        };
+        Some(sum * 2 % (MODULUS + 1))
+    })?;
+    char::from_digit((MODULUS + 1 - product) % MODULUS, 36).map(|c| c.to_ascii_uppercase())
+}
+
+/// Multiplies digits by weights from the right, cycling the weights
+///
+/// Returns `None` if the sum overflows a `u32`.
+fn weighted_sum(payload: &str, weights: &[u32]) -> Option<u32> {
+    if weights.is_empty() {
+        return None;
+    }
+    digits(payload)?
+        .iter()
+        .rev()
+        .zip(weights.iter().cycle())
+        .try_fold(0u32, |sum, (digit, weight)| {
+            sum.checked_add(digit.checked_mul(*weight)?)
+        })
+}
+
+/// Validates a number with a trailing weighted modulus 11 check character
+///
+/// See [`weighted_mod11_check_digit`]; a check value of 10 is written `X`.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::is_valid_weighted_mod11;
+///
+/// // ISBN-10
+/// let weights = [2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// assert!(is_valid_weighted_mod11("0306406152", &weights));
+/// assert!(is_valid_weighted_mod11("080442957X", &weights));
+/// ```
+pub fn is_valid_weighted_mod11(value: &str, weights: &[u32]) -> bool {
+    let Some((payload, check)) = split_check(value) else {
+        return false;
+    };
+    match weighted_mod11_check_digit(payload, weights) {
+        Some(10) => check.eq_ignore_ascii_case(&'X'),
+        Some(digit) => check.to_digit(10) == Some(digit),
+        None => false,
+    }
+}
+
+/// Computes a weighted modulus 11 check value (0–10)
+///
+/// The rightmost payload digit is multiplied by `weights[0]`, the next by
+/// `weights[1]`, and so on, cycling through the weights. The check value
+/// makes the weighted sum, plus the check value itself, divisible by 11.
+/// Whether a result of 10 is written `X` or rejected depends on the scheme.
+/// Returns `None` if the weighted sum overflows a `u32`.
+pub fn weighted_mod11_check_digit(payload: &str, weights: &[u32]) -> Option<u32> {
+    Some((11 - weighted_sum(payload, weights)? % 11) % 11)
+}
+
+/// Validates a number with a trailing weighted modulus 10 check digit
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::checksum::is_valid_weighted_mod10;
+///
+/// // GTIN-13 (EAN-13)
+/// assert!(is_valid_weighted_mod10("4006381333931", &[3, 1]));
+/// assert!(!is_valid_weighted_mod10("4006381333932", &[3, 1]));
+/// ```
+pub fn is_valid_weighted_mod10(value: &str, weights: &[u32]) -> bool {
+    match split_check(value) {
+        Some((payload, check)) => {
+            weighted_mod10_check_digit(payload, weights).map(digit_char) == Some(check)
+        }
+        None => false,
+    }
+}
+
+/// Computes a weighted modulus 10 check digit
+///
+/// Weights are applied from the rightmost payload digit as in
+/// [`weighted_mod11_check_digit`]; `[3, 1]` gives the GTIN/EAN/UPC check digit.
+pub fn weighted_mod10_check_digit(payload: &str, weights: &[u32]) -> Option<u32> {
+    Some((10 - weighted_sum(payload, weights)? % 10) % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn() {
+        assert!(is_valid_luhn("79927398713"));
+        assert!(is_valid_luhn("0"));
+        assert!(!is_valid_luhn(""));
+        assert!(!is_valid_luhn("7992739871a"));
+        assert!(!is_valid_luhn("79927398713 "));
+        assert_eq!(luhn_check_digit(""), Some('0'));
+        assert_eq!(luhn_check_digit("453201511283036"), Some('6'));
+    }
+
+    #[test]
+    fn test_luhn_mod_n() {
+        let decimal = "0123456789";
+        for number in ["79927398713", "4532015112830366", "374245455400126"] {
+            assert!(is_valid_luhn_mod_n(number, decimal));
+        }
+        assert!(!is_valid_luhn_mod_n("79927398714", decimal));
+
+        let base36 = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let check = luhn_mod_n_check_character("HELLO42", base36).unwrap();
+        assert!(is_valid_luhn_mod_n(&format!("HELLO42{}", check), base36));
+        assert!(!is_valid_luhn_mod_n(&format!("HELOL42{}", check), base36));
+        assert_eq!(luhn_mod_n_check_character("hello", base36), None);
+        assert_eq!(luhn_mod_n_check_character("0", "0"), None);
+    }
+
+    #[test]
+    fn test_verhoeff() {
+        assert!(is_valid_verhoeff("2363"));
+        assert_eq!(verhoeff_check_digit("12345"), Some('1'));
+        assert!(is_valid_verhoeff("123451"));
+        assert_eq!(verhoeff_check_digit("142857"), Some('0'));
+        assert!(!is_valid_verhoeff("123415"));
+        assert!(!is_valid_verhoeff(""));
+        assert!(!is_valid_verhoeff("12a"));
+    }
+
+    #[test]
+    fn test_damm() {
+        assert!(is_valid_damm("5724"));
+        assert!(is_valid_damm("0"));
+        assert!(!is_valid_damm("5720"));
+        assert!(!is_valid_damm(""));
+        assert!(is_valid_damm("112946"));
+        assert_eq!(damm_check_digit("11294"), Some('6'));
+    }
+
+    #[test]
+    fn test_iso7064_mod11_2() {
+        assert_eq!(iso7064_mod11_2_check_character("079"), Some('X'));
+        assert!(is_valid_iso7064_mod11_2("079X"));
+        assert!(is_valid_iso7064_mod11_2("079x"));
+        assert!(!is_valid_iso7064_mod11_2("0790"));
+        assert!(!is_valid_iso7064_mod11_2("X"));
+        assert!(!is_valid_iso7064_mod11_2(""));
+    }
+
+    #[test]
+    fn test_iso7064_mod97_10() {
+        // IBAN GB82 WEST 1234 5698 7654 32, rearranged
+        assert!(is_valid_iso7064_mod97_10("WEST12345698765432GB82"));
+        assert!(!is_valid_iso7064_mod97_10("WEST12345698765432GB83"));
+        assert_eq!(
+            iso7064_mod97_10_check_digits("WEST12345698765432GB").unwrap(),
+            "82"
+        );
+        assert_eq!(mod97("1-2"), None);
+        assert!(!is_valid_iso7064_mod97_10("1"));
+    }
+
+    #[test]
+    fn test_iso7064_mod37_36() {
+        assert!(is_valid_iso7064_mod37_36("a12425gabc1234002m"));
+        assert!(!is_valid_iso7064_mod37_36("A12425GABC1234002N"));
+        assert!(!is_valid_iso7064_mod37_36("A12425GACB1234002M"));
+        assert_eq!(iso7064_mod37_36_check_character("A-1"), None);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let isbn = [2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(weighted_mod11_check_digit("030640615", &isbn), Some(2));
+        assert!(!is_valid_weighted_mod11("0306406153", &isbn));
+        assert!(!is_valid_weighted_mod11("", &isbn));
+
+        assert_eq!(weighted_mod10_check_digit("400638133393", &[3, 1]), Some(1));
+        assert!(is_valid_weighted_mod10("036000291452", &[3, 1])); // UPC-A
+        assert_eq!(weighted_mod10_check_digit("123", &[]), None);
+
+        // Overflowing sums are rejected rather than wrapped
+        let huge = [u32::MAX];
+        assert_eq!(weighted_mod10_check_digit("11", &huge), None);
+        assert_eq!(weighted_mod11_check_digit("11", &huge), None);
+        assert_eq!(weighted_mod10_check_digit("9", &[u32::MAX / 9]), Some(8));
+        for check in '0'..='9' {
+            assert!(!is_valid_weighted_mod10(&format!("11{}", check), &huge));
+            assert!(!is_valid_weighted_mod11(&format!("11{}", check), &huge));
+        }
+    }
+}
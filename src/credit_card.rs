@@ -1,5 +1,14 @@
 //! Credit card validation functions
 
+use crate::checksum::is_valid_luhn;
+use crate::date::{days_in_month, parse_date};
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
+use std::fmt;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 /// Validates a credit card number using the Luhn algorithm
 ///
 /// # Examples
@@ -21,64 +30,750 @@ pub fn is_valid_credit_card(card_number: &str) -> bool {
         return false;
     }
 
-    luhn_check(&cleaned)
+    is_valid_luhn(&cleaned)
+}
+
+/// Identifies the credit card type based on the card number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardType {
+    Visa,
+    MasterCard,
+    Amex,
+    Discover,
+    /// JCB
+    Jcb,
+    /// Diners Club International
+    DinersClub,
+    /// China UnionPay
+    UnionPay,
+    /// Maestro
+    Maestro,
+    /// Mir (Russia)
+    Mir,
+    /// RuPay (India)
+    RuPay,
+    /// Elo (Brazil)
+    Elo,
+    /// Hipercard (Brazil)
+    Hipercard,
+    /// Troy (Turkey)
+    Troy,
+    /// Verve (Nigeria)
+    Verve,
+    Unknown,
+}
+
+impl CardType {
+    /// Returns the card number lengths issued by this network
+    ///
+    /// `Unknown` accepts any length from 13 to 19 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validator_rs::credit_card::CardType;
+    ///
+    /// assert_eq!(CardType::Amex.lengths(), &[15]);
+    /// assert_eq!(CardType::Visa.lengths(), &[13, 16, 19]);
+    /// ```
+    pub fn lengths(self) -> &'static [usize] {
+        match self {
+            CardType::Visa => &[13, 16, 19],
+            CardType::MasterCard | CardType::Elo | CardType::RuPay | CardType::Troy => &[16],
+            CardType::Amex => &[15],
+            CardType::Discover | CardType::Jcb | CardType::UnionPay | CardType::Mir => {
+                &[16, 17, 18, 19]
+            }
+            CardType::DinersClub => &[14, 16, 19],
+            CardType::Maestro => &[12, 13, 14, 15, 16, 17, 18, 19],
+            CardType::Hipercard => &[16, 19],
+            CardType::Verve => &[16, 18, 19],
+            CardType::Unknown => &[13, 14, 15, 16, 17, 18, 19],
+        }
+    }
+
+    /// Returns the number of digits in the card security code (CVV/CVC)
+    ///
+    /// Amex uses a 4-digit code (CID); all other networks use 3 digits.
+    pub fn cvv_length(self) -> usize {
+        match self {
+            CardType::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// Issuer identification number ranges, most specific first
+///
+/// Each entry is an inclusive range of card number prefixes of equal length.
+const IIN_RANGES: &[(&str, &str, CardType)] = &[
+    ("401178", "401179", CardType::Elo),
+    ("431274", "431274", CardType::Elo),
+    ("438935", "438935", CardType::Elo),
+    ("451416", "451416", CardType::Elo),
+    ("457393", "457393", CardType::Elo),
+    ("457631", "457632", CardType::Elo),
+    ("504175", "504175", CardType::Elo),
+    ("506699", "506778", CardType::Elo),
+    ("509000", "509999", CardType::Elo),
+    ("627780", "627780", CardType::Elo),
+    ("636297", "636297", CardType::Elo),
+    ("636368", "636368", CardType::Elo),
+    ("650031", "650033", CardType::Elo),
+    ("650035", "650051", CardType::Elo),
+    ("650405", "650439", CardType::Elo),
+    ("650485", "650538", CardType::Elo),
+    ("650541", "650598", CardType::Elo),
+    ("650700", "650718", CardType::Elo),
+    ("650720", "650727", CardType::Elo),
+    ("650901", "650978", CardType::Elo),
+    ("651652", "651679", CardType::Elo),
+    ("655000", "655019", CardType::Elo),
+    ("655021", "655058", CardType::Elo),
+    ("384100", "384100", CardType::Hipercard),
+    ("384140", "384140", CardType::Hipercard),
+    ("384160", "384160", CardType::Hipercard),
+    ("606282", "606282", CardType::Hipercard),
+    ("637095", "637095", CardType::Hipercard),
+    ("637568", "637568", CardType::Hipercard),
+    ("637599", "637599", CardType::Hipercard),
+    ("637609", "637609", CardType::Hipercard),
+    ("637612", "637612", CardType::Hipercard),
+    ("506099", "506198", CardType::Verve),
+    ("507865", "507964", CardType::Verve),
+    ("650002", "650027", CardType::Verve),
+    ("508500", "508999", CardType::RuPay),
+    ("606985", "607984", CardType::RuPay),
+    ("608001", "608500", CardType::RuPay),
+    ("652150", "653149", CardType::RuPay),
+    ("9792", "9792", CardType::Troy),
+    ("2200", "2204", CardType::Mir),
+    ("4", "4", CardType::Visa),
+    ("51", "55", CardType::MasterCard),
+    ("2221", "2720", CardType::MasterCard),
+    ("34", "34", CardType::Amex),
+    ("37", "37", CardType::Amex),
+    ("6011", "6011", CardType::Discover),
+    ("644", "649", CardType::Discover),
+    ("65", "65", CardType::Discover),
+    ("3528", "3589", CardType::Jcb),
+    ("300", "305", CardType::DinersClub),
+    ("36", "36", CardType::DinersClub),
+    ("38", "39", CardType::DinersClub),
+    ("62", "62", CardType::UnionPay),
+    ("81", "81", CardType::UnionPay),
+    ("5018", "5018", CardType::Maestro),
+    ("5020", "5020", CardType::Maestro),
+    ("5038", "5038", CardType::Maestro),
+    ("5893", "5893", CardType::Maestro),
+    ("6304", "6304", CardType::Maestro),
+    ("6759", "6759", CardType::Maestro),
+    ("6761", "6763", CardType::Maestro),
+    ("50", "50", CardType::Maestro),
+    ("56", "58", CardType::Maestro),
+    ("67", "67", CardType::Maestro),
+];
+
+/// Determines the type of credit card
+///
+/// Matches the issuer identification number (the leading digits) against the
+/// known ranges of each network, ignoring spaces and dashes.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{get_card_type, CardType};
+///
+/// assert_eq!(get_card_type("4532015112830366"), CardType::Visa);
+/// assert_eq!(get_card_type("2223 0031 2200 3222"), CardType::MasterCard);
+/// assert_eq!(get_card_type("2200 0000 0000 0004"), CardType::Mir);
+/// ```
+pub fn get_card_type(card_number: &str) -> CardType {
+    let cleaned = card_number.replace([' ', '-'], "");
+
+    IIN_RANGES
+        .iter()
+        .find(|(start, end, _)| {
+            cleaned
+                .get(..start.len())
+                .is_some_and(|prefix| *start <= prefix && prefix <= *end)
+        })
+        .map_or(CardType::Unknown, |&(_, _, card_type)| card_type)
+}
+
+/// Returns `true` if the card number length is issued by its detected network
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::has_valid_network_length;
+///
+/// assert!(has_valid_network_length("374245455400126"));
+/// assert!(!has_valid_network_length("3742454554001265"));
+/// ```
+pub fn has_valid_network_length(card_number: &str) -> bool {
+    let length = card_number
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .count();
+    get_card_type(card_number).lengths().contains(&length)
+}
+
+/// Validates a credit card number, also requiring a length issued by its network
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{is_valid_credit_card, is_valid_credit_card_strict};
+///
+/// // A Luhn-valid 16-digit number with an Amex prefix
+/// assert!(is_valid_credit_card("3400000000000000"));
+/// assert!(!is_valid_credit_card_strict("3400000000000000"));
+/// assert!(is_valid_credit_card_strict("340000000000009"));
+/// ```
+pub fn is_valid_credit_card_strict(card_number: &str) -> bool {
+    is_valid_credit_card(card_number) && has_valid_network_length(card_number)
+}
+
+/// Reason a card number failed [`is_credit_card`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardError {
+    /// The input is empty
+    Empty,
+    /// The number contains a character other than a digit or allowed separator
+    InvalidCharacter(char),
+    /// The number of digits is not valid (for the network, if required)
+    InvalidLength(usize),
+    /// The Luhn check digit does not match
+    InvalidChecksum,
+    /// The detected network is not in [`CardOptions::allowed_networks`]
+    NetworkNotAllowed(CardType),
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardError::Empty => f.write_str("card number is empty"),
+            CardError::InvalidCharacter(c) => {
+                write!(f, "card number contains invalid character '{}'", c)
+            }
+            CardError::InvalidLength(length) => {
+                write!(f, "card number has an invalid length of {} digits", length)
+            }
+            CardError::InvalidChecksum => f.write_str("card number has an invalid check digit"),
+            CardError::NetworkNotAllowed(card_type) => {
+                write!(f, "card network {:?} is not accepted", card_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CardError {}
+
+/// Options for [`is_credit_card`]
+#[derive(Debug, Clone)]
+pub struct CardOptions {
+    /// If non-empty, the detected network must be one of these (default: empty)
+    pub allowed_networks: Vec<CardType>,
+    /// Allow spaces and dashes between digits (default: true)
+    pub allow_separators: bool,
+    /// Require the length to match the detected network (default: false)
+    pub require_network_length: bool,
+}
+
+impl Default for CardOptions {
+    fn default() -> Self {
+        Self {
+            allowed_networks: Vec::new(),
+            allow_separators: true,
+            require_network_length: false,
+        }
+    }
+}
+
+impl CardOptions {
+    /// Create default card options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the accepted networks
+    pub fn allowed_networks(mut self, networks: impl IntoIterator<Item = CardType>) -> Self {
+        self.allowed_networks = networks.into_iter().collect();
+        self
+    }
+
+    /// Set whether spaces and dashes are allowed
+    pub fn allow_separators(mut self, allow: bool) -> Self {
+        self.allow_separators = allow;
+        self
+    }
+
+    /// Set whether the length must match the detected network
+    pub fn require_network_length(mut self, require: bool) -> Self {
+        self.require_network_length = require;
+        self
+    }
+}
+
+/// A card number accepted by [`is_credit_card`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardInfo {
+    card_type: CardType,
+    number: String,
+}
+
+impl CardInfo {
+    /// Returns the detected network
+    pub fn card_type(&self) -> CardType {
+        self.card_type
+    }
+
+    /// Returns the card number without separators
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+/// Validates a credit card number with options, returning its network
+///
+/// Uses the default [`CardOptions`] if `None` is provided.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{is_credit_card, CardError, CardOptions, CardType};
+///
+/// let card = is_credit_card("4532 0151 1283 0366", None).unwrap();
+/// assert_eq!(card.card_type(), CardType::Visa);
+/// assert_eq!(card.number(), "4532015112830366");
+///
+/// let options = CardOptions::new().allowed_networks([CardType::Visa, CardType::MasterCard]);
+/// assert_eq!(
+///     is_credit_card("374245455400126", Some(options)),
+///     Err(CardError::NetworkNotAllowed(CardType::Amex))
+/// );
+/// assert_eq!(is_credit_card("4532015112830367", None), Err(CardError::InvalidChecksum));
+/// ```
+pub fn is_credit_card(value: &str, options: Option<CardOptions>) -> Result<CardInfo, CardError> {
+    let opts = options.unwrap_or_default();
+    if value.is_empty() {
+        return Err(CardError::Empty);
+    }
+
+    let mut number = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            ' ' | '-' if opts.allow_separators => {}
+            _ => return Err(CardError::InvalidCharacter(c)),
+        }
+    }
+
+    let card_type = get_card_type(&number);
+    let valid_length = if opts.require_network_length {
+        card_type.lengths().contains(&number.len())
+    } else {
+        (13..=19).contains(&number.len())
+    };
+    if !valid_length {
+        return Err(CardError::InvalidLength(number.len()));
+    }
+
+    if !is_valid_luhn(&number) {
+        return Err(CardError::InvalidChecksum);
+    }
+
+    if !opts.allowed_networks.is_empty() && !opts.allowed_networks.contains(&card_type) {
+        return Err(CardError::NetworkNotAllowed(card_type));
+    }
+
+    Ok(CardInfo { card_type, number })
 }
 
-/// Implements the Luhn algorithm for credit card validation
-fn luhn_check(number: &str) -> bool {
-    let digits: Vec<u32> = number
+/// Formats a card number in the digit groups printed on the card
+///
+/// Amex numbers are grouped 4-6-5, 14-digit Diners Club numbers 4-6-4, and
+/// all other numbers in groups of four. Existing spaces and dashes are
+/// ignored. Returns `None` if the input contains other non-digit characters.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::format_pan;
+///
+/// assert_eq!(format_pan("4532015112830366").unwrap(), "4532 0151 1283 0366");
+/// assert_eq!(format_pan("3742-454554-00126").unwrap(), "3742 454554 00126");
+/// assert_eq!(format_pan("4532x"), None);
+/// ```
+pub fn format_pan(card_number: &str) -> Option<String> {
+    let digits: String = card_number
         .chars()
-        .filter_map(|c| c.to_digit(10))
+        .filter(|c| !matches!(c, ' ' | '-'))
         .collect();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
 
-    if digits.is_empty() {
-        return false;
+    let groups: &[usize] = match (get_card_type(&digits), digits.len()) {
+        (CardType::Amex, 15) => &[4, 6, 5],
+        (CardType::DinersClub, 14) => &[4, 6, 4],
+        _ => &[],
+    };
+
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 4);
+    let mut rest = digits.as_str();
+    let mut sizes = groups.iter().copied();
+    while !rest.is_empty() {
+        let size = sizes.next().unwrap_or(4).min(rest.len());
+        if !formatted.is_empty() {
+            formatted.push(' ');
+        }
+        formatted.push_str(&rest[..size]);
+        rest = &rest[size..];
     }
+    Some(formatted)
+}
+
+/// Masks a card number, keeping only the first six and last four digits
+///
+/// This is the most PCI DSS allows to be displayed. Numbers shorter than 13
+/// digits keep only the last four. Separators and other characters are kept
+/// in place.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::mask_pan;
+///
+/// assert_eq!(mask_pan("4532015112830366"), "453201******0366");
+/// assert_eq!(mask_pan("4532 0151 1283 0366"), "4532 01** **** 0366");
+/// ```
+pub fn mask_pan(card_number: &str) -> String {
+    let digits = card_number.chars().filter(char::is_ascii_digit).count();
+    let visible_start = if digits >= 13 { 6 } else { 0 };
+
+    let mut index = 0;
+    card_number
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            let masked = index >= visible_start && index + 4 < digits;
+            index += 1;
+            if masked {
+                '*'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Masks every Luhn-valid card number found in free text
+///
+/// Card numbers are runs of 13 to 19 digits, optionally grouped with single
+/// spaces or dashes, that are not part of a longer word. Each one is replaced
+/// with [`mask_pan`]. Intended for sanitising logs; numbers that merely pass
+/// the Luhn check are masked too.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::redact_pans_in_text;
+///
+/// assert_eq!(
+///     redact_pans_in_text("charged 4532-0151-1283-0366 for order 1234567890123"),
+///     "charged 4532-01**-****-0366 for order 1234567890123"
+/// );
+/// ```
+pub fn redact_pans_in_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
 
-    let mut sum = 0;
-    let mut alternate = false;
+    while index < chars.len() {
+        let starts_run =
+            chars[index].is_ascii_digit() && (index == 0 || !chars[index - 1].is_alphanumeric());
+        if !starts_run {
+            output.push(chars[index]);
+            index += 1;
+            continue;
+        }
 
-    for &digit in digits.iter().rev() {
-        let mut n = digit;
-        if alternate {
-            n *= 2;
-            if n > 9 {
-                n -= 9;
+        // Digit groups of the run, as (start, end) char indices
+        let mut groups = Vec::new();
+        let mut end = index;
+        loop {
+            let start = end;
+            while end < chars.len() && chars[end].is_ascii_digit() {
+                end += 1;
             }
+            groups.push((start, end));
+            let grouped = end + 1 < chars.len()
+                && matches!(chars[end], ' ' | '-')
+                && chars[end + 1].is_ascii_digit();
+            if !grouped {
+                break;
+            }
+            end += 1;
         }
-        sum += n;
-        alternate = !alternate;
+
+        if end < chars.len() && chars[end].is_alphanumeric() {
+            output.extend(&chars[index..end]);
+        } else {
+            redact_groups(&chars, &groups, &mut output);
+        }
+        index = end;
     }
+    output
+}
 
-    sum % 10 == 0
+/// Masks the longest Luhn-valid card numbers made of whole digit groups
+fn redact_groups(chars: &[char], groups: &[(usize, usize)], output: &mut String) {
+    let mut first = 0;
+    let mut copied = groups[0].0;
+    while first < groups.len() {
+        // Extend forward, stopping once no card number can be this long
+        let mut digits = String::new();
+        let mut found = None;
+        for (last, &(start, end)) in groups.iter().enumerate().skip(first) {
+            digits.extend(&chars[start..end]);
+            if digits.len() > 19 {
+                break;
+            }
+            if digits.len() >= 13 && is_valid_luhn(&digits) {
+                found = Some(last);
+            }
+        }
+        match found {
+            Some(last) => {
+                let (start, end) = (groups[first].0, groups[last].1);
+                output.extend(&chars[copied..start]);
+                let candidate: String = chars[start..end].iter().collect();
+                output.push_str(&mask_pan(&candidate));
+                copied = end;
+                first = last + 1;
+            }
+            None => first += 1,
+        }
+    }
+    output.extend(&chars[copied..groups[groups.len() - 1].1]);
 }
 
-/// Identifies the credit card type based on the card number
-#[derive(Debug, PartialEq, Eq)]
-pub enum CardType {
-    Visa,
-    MasterCard,
-    Amex,
-    Discover,
-    Unknown,
+/// Reason a card expiry date failed [`is_valid_expiry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryError {
+    /// The value is not in `MM/YY`, `MM/YYYY` or `MMYY` format, or has a
+    /// four-digit year before 2000
+    InvalidFormat,
+    /// The month is not between 01 and 12
+    InvalidMonth,
+    /// The card expired before the given date
+    Expired,
+    /// The given "today" is not a valid ISO 8601 date
+    InvalidToday,
 }
 
-/// Determines the type of credit card
-pub fn get_card_type(card_number: &str) -> CardType {
-    let cleaned = card_number.replace([' ', '-'], "");
-    
-    if cleaned.starts_with('4') {
-        CardType::Visa
-    } else if cleaned.starts_with("51") || cleaned.starts_with("52") || 
-              cleaned.starts_with("53") || cleaned.starts_with("54") || 
-              cleaned.starts_with("55") {
-        CardType::MasterCard
-    } else if cleaned.starts_with("34") || cleaned.starts_with("37") {
-        CardType::Amex
-    } else if cleaned.starts_with("6011") || cleaned.starts_with("65") {
-        CardType::Discover
-    } else {
-        CardType::Unknown
+impl fmt::Display for ExpiryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpiryError::InvalidFormat => f.write_str("expiry date must be MM/YY, MM/YYYY or MMYY"),
+            ExpiryError::InvalidMonth => f.write_str("expiry month must be between 01 and 12"),
+            ExpiryError::Expired => f.write_str("card has expired"),
+            ExpiryError::InvalidToday => f.write_str("reference date is not a valid date"),
+        }
+    }
+}
+
+impl std::error::Error for ExpiryError {}
+
+/// A card expiry month and year
+///
+/// Cards are valid through the last day of their expiry month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardExpiry {
+    year: i32,
+    month: u32,
+}
+
+impl CardExpiry {
+    /// Parses an expiry date in `MM/YY`, `MM/YYYY` or `MMYY` format
+    ///
+    /// Two-digit years are in the 2000s, and four-digit years must be 2000 or
+    /// later. Spaces around the `/` are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validator_rs::credit_card::{CardExpiry, ExpiryError};
+    ///
+    /// let expiry = CardExpiry::parse("09/27").unwrap();
+    /// assert_eq!((expiry.month(), expiry.year()), (9, 2027));
+    /// assert_eq!(expiry.last_valid_date(), "2027-09-30");
+    ///
+    /// assert_eq!(CardExpiry::parse("0928").unwrap().year(), 2028);
+    /// assert_eq!(CardExpiry::parse("13/27"), Err(ExpiryError::InvalidMonth));
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, ExpiryError> {
+        let (month, year) = match value.split_once('/') {
+            Some((month, year)) => (month.trim_end(), year.trim_start()),
+            None if value.len() == 4 && value.is_ascii() => value.split_at(2),
+            None => return Err(ExpiryError::InvalidFormat),
+        };
+
+        let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if month.len() != 2
+            || !matches!(year.len(), 2 | 4)
+            || !all_digits(month)
+            || !all_digits(year)
+        {
+            return Err(ExpiryError::InvalidFormat);
+        }
+
+        let month: u32 = month.parse().map_err(|_| ExpiryError::InvalidFormat)?;
+        let year = match (year.len(), year.parse::<i32>()) {
+            (2, Ok(year)) => year + 2000,
+            (4, Ok(year)) if year >= 2000 => year,
+            _ => return Err(ExpiryError::InvalidFormat),
+        };
+        days_in_month(year, month).ok_or(ExpiryError::InvalidMonth)?;
+
+        Ok(Self { year, month })
+    }
+
+    /// Returns the expiry month (1-12)
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the four-digit expiry year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the last day the card is valid, as an ISO 8601 date
+    pub fn last_valid_date(&self) -> String {
+        let day = days_in_month(self.year, self.month).unwrap_or(31);
+        format!("{:04}-{:02}-{:02}", self.year, self.month, day)
+    }
+
+    /// Returns `true` if the card has expired on `today` (an ISO 8601 date)
+    pub fn is_expired_on(&self, today: &str) -> Result<bool, ExpiryError> {
+        let (year, month, _) = parse_date(today).ok_or(ExpiryError::InvalidToday)?;
+        Ok((self.year, self.month) < (year, month))
+    }
+}
+
+impl std::str::FromStr for CardExpiry {
+    type Err = ExpiryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates a card expiry date against a caller-supplied "today"
+///
+/// Accepts `MM/YY`, `MM/YYYY` and `MMYY`. `today` is an ISO 8601 date
+/// (`YYYY-MM-DD`); a card expiring in the current month is still valid.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{is_valid_expiry, ExpiryError};
+///
+/// assert!(is_valid_expiry("12/25", "2025-12-31").is_ok());
+/// assert_eq!(is_valid_expiry("11/2025", "2025-12-01"), Err(ExpiryError::Expired));
+/// ```
+pub fn is_valid_expiry(value: &str, today: &str) -> Result<CardExpiry, ExpiryError> {
+    let expiry = CardExpiry::parse(value)?;
+    if expiry.is_expired_on(today)? {
+        return Err(ExpiryError::Expired);
+    }
+    Ok(expiry)
+}
+
+/// Validates a card security code (CVV/CVC) for a card network
+///
+/// The code must be exactly [`CardType::cvv_length`] digits: 4 for Amex and
+/// 3 otherwise. Use [`get_card_type`] to find the network of a card number.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{get_card_type, is_valid_cvv, CardType};
+///
+/// assert!(is_valid_cvv("123", get_card_type("4532015112830366")));
+/// assert!(is_valid_cvv("1234", get_card_type("374245455400126")));
+/// assert!(!is_valid_cvv("123", CardType::Amex));
+/// assert!(!is_valid_cvv("12a", CardType::Visa));
+/// ```
+pub fn is_valid_cvv(cvv: &str, card_type: CardType) -> bool {
+    cvv.len() == card_type.cvv_length() && cvv.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Configurable credit card validator implementing [`Validator`]
+///
+/// Fails with code `credit_card.invalid`, or `credit_card.type_not_allowed`
+/// (with a `card_type` parameter) when the card type is not accepted. With
+/// `require_network_length`, numbers whose length is not issued by their
+/// network are also invalid.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::{CardType, CreditCardValidator};
+/// use validator_rs::validator::Validator;
+///
+/// let validator = CreditCardValidator::new().allowed_types([CardType::Visa]);
+/// assert!(validator.validate("4532015112830366", "card").is_ok());
+/// assert!(validator.validate("5425233430109903", "card").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CreditCardValidator {
+    /// If non-empty, the card type must be one of these
+    pub allowed_types: Vec<CardType>,
+    /// Require the length to match the detected network (see [`CardType::lengths`])
+    pub require_network_length: bool,
+}
+
+impl CreditCardValidator {
+    /// Create a new CreditCardValidator accepting any card type
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the accepted card types
+    pub fn allowed_types(mut self, types: impl IntoIterator<Item = CardType>) -> Self {
+        self.allowed_types = types.into_iter().collect();
+        self
+    }
+
+    /// Set whether the length must match the detected network
+    pub fn require_network_length(mut self, require: bool) -> Self {
+        self.require_network_length = require;
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for CreditCardValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let options = CardOptions::new()
+            .allowed_networks(self.allowed_types.iter().copied())
+            .require_network_length(self.require_network_length);
+
+        match is_credit_card(value.as_ref(), Some(options)) {
+            Ok(_) => Ok(()),
+            Err(CardError::NetworkNotAllowed(card_type)) => {
+                Err(ValidationError::new(field, "Card type is not accepted")
+                    .with_code("credit_card.type_not_allowed")
+                    .with_param("card_type", format!("{:?}", card_type)))
+            }
+            Err(_) => Err(ValidationError::new(field, "Invalid credit card number")
+                .with_code("credit_card.invalid")),
+        }
     }
 }
 
@@ -119,8 +814,250 @@ mod tests {
 
     #[test]
     fn test_luhn_algorithm() {
-        assert!(luhn_check("79927398713"));
-        assert!(!luhn_check("79927398714"));
+        assert!(is_valid_luhn("79927398713"));
+        assert!(!is_valid_luhn("79927398714"));
     }
-}
 
+    #[test]
+    fn test_credit_card_validator() {
+        assert!(CreditCardValidator::new()
+            .validate("374245455400126", "card")
+            .is_ok());
+        assert!(CreditCardValidator::new()
+            .validate("4532015112830367", "card")
+            .is_err());
+
+        let validator =
+            CreditCardValidator::new().allowed_types([CardType::Visa, CardType::MasterCard]);
+        assert!(validator.validate("5425233430109903", "card").is_ok());
+
+        let error = validator.validate("374245455400126", "card").unwrap_err();
+        assert_eq!(error.code, "credit_card.type_not_allowed");
+        assert_eq!(error.param("card_type").unwrap().to_string(), "Amex");
+    }
+
+    #[test]
+    fn test_card_networks() {
+        let cases = [
+            ("2221000000000009", CardType::MasterCard),
+            ("2720999999999996", CardType::MasterCard),
+            ("2721000000000004", CardType::Unknown),
+            ("3530111333300000", CardType::Jcb),
+            ("30569309025904", CardType::DinersClub),
+            ("38520000023237", CardType::DinersClub),
+            ("6200000000000005", CardType::UnionPay),
+            ("6759649826438453", CardType::Maestro),
+            ("5018000000000009", CardType::Maestro),
+            ("2200000000000004", CardType::Mir),
+            ("6521500000000003", CardType::RuPay),
+            ("5085000000000004", CardType::RuPay),
+            ("6362970000457013", CardType::Elo),
+            ("4011780000000007", CardType::Elo),
+            ("6062825624254001", CardType::Hipercard),
+            ("9792000000000001", CardType::Troy),
+            ("5061000000000000000", CardType::Verve),
+            ("6500020000000000", CardType::Verve),
+            ("6500010000000000", CardType::Discover),
+            ("6445644564456445", CardType::Discover),
+        ];
+        for (number, card_type) in cases {
+            assert_eq!(get_card_type(number), card_type, "{}", number);
+        }
+        assert_eq!(get_card_type(""), CardType::Unknown);
+        assert_eq!(get_card_type("22"), CardType::Unknown);
+    }
+
+    #[test]
+    fn test_network_lengths() {
+        assert!(has_valid_network_length("4222222222222"));
+        assert!(has_valid_network_length("4532 0151 1283 0366"));
+        assert!(!has_valid_network_length("45320151128303"));
+        assert!(has_valid_network_length("30569309025904"));
+        assert!(!has_valid_network_length("542523343010990"));
+
+        assert!(is_valid_credit_card_strict("4532015112830366"));
+        assert!(is_valid_credit_card("453201511283034"));
+        assert!(!is_valid_credit_card_strict("453201511283034"));
+
+        let validator = CreditCardValidator::new().require_network_length(true);
+        assert!(validator.validate("374245455400126", "card").is_ok());
+        assert!(validator.validate("3400000000000000", "card").is_err());
+    }
+
+    #[test]
+    fn test_is_credit_card() {
+        let card = is_credit_card("5425-2334-3010-9903", None).unwrap();
+        assert_eq!(card.card_type(), CardType::MasterCard);
+        assert_eq!(card.number(), "5425233430109903");
+
+        assert_eq!(is_credit_card("", None), Err(CardError::Empty));
+        assert_eq!(
+            is_credit_card("4532O15112830366", None),
+            Err(CardError::InvalidCharacter('O'))
+        );
+        assert_eq!(
+            is_credit_card("4222", None),
+            Err(CardError::InvalidLength(4))
+        );
+        assert_eq!(
+            is_credit_card("1234567890123456", None),
+            Err(CardError::InvalidChecksum)
+        );
+
+        let options = CardOptions::new().allow_separators(false);
+        assert_eq!(
+            is_credit_card("4532 0151 1283 0366", Some(options.clone())),
+            Err(CardError::InvalidCharacter(' '))
+        );
+        assert!(is_credit_card("4532015112830366", Some(options)).is_ok());
+    }
+
+    #[test]
+    fn test_card_options_network_length() {
+        let options = CardOptions::new().require_network_length(true);
+        assert!(is_credit_card("3400000000000000", None).is_ok());
+        assert_eq!(
+            is_credit_card("3400000000000000", Some(options.clone())),
+            Err(CardError::InvalidLength(16))
+        );
+        // 12-digit Maestro numbers are only accepted by network length
+        assert_eq!(
+            is_credit_card("501800000009", None),
+            Err(CardError::InvalidLength(12))
+        );
+        let card = is_credit_card("501800000009", Some(options)).unwrap();
+        assert_eq!(card.card_type(), CardType::Maestro);
+    }
+
+    #[test]
+    fn test_format_pan() {
+        assert_eq!(
+            format_pan("4532 0151 1283 0366").unwrap(),
+            "4532 0151 1283 0366"
+        );
+        assert_eq!(format_pan("374245455400126").unwrap(), "3742 454554 00126");
+        assert_eq!(format_pan("30569309025904").unwrap(), "3056 930902 5904");
+        assert_eq!(
+            format_pan("6200000000000000005").unwrap(),
+            "6200 0000 0000 0000 005"
+        );
+        assert_eq!(format_pan("4222222222222").unwrap(), "4222 2222 2222 2");
+        assert_eq!(format_pan(""), None);
+        assert_eq!(format_pan(" - "), None);
+    }
+
+    #[test]
+    fn test_mask_pan() {
+        assert_eq!(mask_pan("374245455400126"), "374245*****0126");
+        assert_eq!(mask_pan("3742 454554 00126"), "3742 45**** *0126");
+        assert_eq!(mask_pan("501800000009"), "********0009");
+        assert_eq!(mask_pan("1234"), "1234");
+        assert_eq!(mask_pan(""), "");
+    }
+
+    #[test]
+    fn test_redact_pans_in_text() {
+        assert_eq!(
+            redact_pans_in_text("cards: 4532015112830366, 374245455400126."),
+            "cards: 453201******0366, 374245*****0126."
+        );
+        assert_eq!(
+            redact_pans_in_text("pan=5425 2334 3010 9903 cvv 123"),
+            "pan=5425 23** **** 9903 cvv 123"
+        );
+        // Trailing groups that are not part of the number are kept
+        assert_eq!(
+            redact_pans_in_text("4532 0151 1283 0366 2024"),
+            "4532 01** **** 0366 2024"
+        );
+        // Not Luhn-valid, too long, or part of a longer token
+        let unchanged = [
+            "order 4532015112830367",
+            "id 45320151128303660000",
+            "hash a4532015112830366",
+            "hash 4532015112830366b",
+            "ünïcödé 12-34",
+        ];
+        for text in unchanged {
+            assert_eq!(redact_pans_in_text(text), text);
+        }
+        assert_eq!(
+            redact_pans_in_text("über 4532015112830366 €"),
+            "über 453201******0366 €"
+        );
+
+        // Long runs of short groups are scanned in linear time
+        let text = "1 ".repeat(20_000);
+        assert_eq!(redact_pans_in_text(&text), text);
+    }
+
+    #[test]
+    fn test_card_expiry() {
+        for value in ["07/26", "07 / 26", "07/2026", "0726"] {
+            let expiry = CardExpiry::parse(value).unwrap();
+            assert_eq!((expiry.month(), expiry.year()), (7, 2026), "{}", value);
+        }
+        assert_eq!(
+            "02/28".parse::<CardExpiry>().unwrap().last_valid_date(),
+            "2028-02-29"
+        );
+
+        for value in [
+            "", "7/26", "07/6", "07/026", "072026", "07-26", "ab/cd", "+1/26", "1é1", "é/26",
+            "01/0026", "01/0000", "01/1999",
+        ] {
+            assert_eq!(
+                CardExpiry::parse(value),
+                Err(ExpiryError::InvalidFormat),
+                "{}",
+                value
+            );
+        }
+        assert_eq!(CardExpiry::parse("00/26"), Err(ExpiryError::InvalidMonth));
+        assert_eq!(CardExpiry::parse("1326"), Err(ExpiryError::InvalidMonth));
+        assert_eq!(CardExpiry::parse("01/2000").unwrap().year(), 2000);
+        assert_eq!(CardExpiry::parse("01/00").unwrap().year(), 2000);
+        assert!(ExpiryError::InvalidFormat.to_string().contains("MMYY"));
+        assert_eq!(
+            is_valid_expiry("1é1", "2026-01-01"),
+            Err(ExpiryError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_is_valid_expiry() {
+        assert!(is_valid_expiry("07/26", "2026-07-31").is_ok());
+        assert!(is_valid_expiry("01/27", "2026-12-15").is_ok());
+        assert_eq!(
+            is_valid_expiry("06/26", "2026-07-01"),
+            Err(ExpiryError::Expired)
+        );
+        assert_eq!(
+            is_valid_expiry("12/25", "2026-01-01"),
+            Err(ExpiryError::Expired)
+        );
+        assert_eq!(
+            is_valid_expiry("07/26", "2026-02-30"),
+            Err(ExpiryError::InvalidToday)
+        );
+        assert_eq!(
+            is_valid_expiry("07/26", "today"),
+            Err(ExpiryError::InvalidToday)
+        );
+        assert_eq!(
+            is_valid_expiry("7/26", "2026-01-01"),
+            Err(ExpiryError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_is_valid_cvv() {
+        assert_eq!(CardType::Amex.cvv_length(), 4);
+        assert_eq!(CardType::Unknown.cvv_length(), 3);
+        assert!(is_valid_cvv("000", CardType::MasterCard));
+        assert!(!is_valid_cvv("1234", CardType::Visa));
+        assert!(!is_valid_cvv("12", CardType::Visa));
+        assert!(!is_valid_cvv("", CardType::Amex));
+        assert!(!is_valid_cvv("１２３", CardType::Visa));
+    }
+}
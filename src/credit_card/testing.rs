@@ -0,0 +1,206 @@
+//! Test card numbers for exercising payment code without real PANs
+//!
+//! Enabled with the `testing` feature. [`TEST_CARDS`] lists sandbox numbers
+//! published by payment providers, and [`PanGenerator`] produces Luhn-valid
+//! numbers for any network and length.
+
+use super::{get_card_type, CardType, IIN_RANGES};
+use crate::checksum::luhn_check_digit;
+
+/// A well-known sandbox card number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestCard {
+    /// The network the number belongs to
+    pub card_type: CardType,
+    /// The card number, digits only
+    pub number: &'static str,
+}
+
+/// Sandbox card numbers accepted by common payment provider test environments
+///
+/// Networks without widely published sandbox numbers (RuPay, Verve) are not
+/// listed; use [`PanGenerator`] for those.
+pub const TEST_CARDS: &[TestCard] = &[
+    card(CardType::Visa, "4242424242424242"),
+    card(CardType::Visa, "4111111111111111"),
+    card(CardType::Visa, "4012888888881881"),
+    card(CardType::Visa, "4222222222222"),
+    card(CardType::MasterCard, "5555555555554444"),
+    card(CardType::MasterCard, "5105105105105100"),
+    card(CardType::MasterCard, "2223003122003222"),
+    card(CardType::Amex, "378282246310005"),
+    card(CardType::Amex, "371449635398431"),
+    card(CardType::Discover, "6011111111111117"),
+    card(CardType::Discover, "6011000990139424"),
+    card(CardType::DinersClub, "30569309025904"),
+    card(CardType::DinersClub, "38520000023237"),
+    card(CardType::DinersClub, "36227206271667"),
+    card(CardType::Jcb, "3530111333300000"),
+    card(CardType::Jcb, "3566002020360505"),
+    card(CardType::UnionPay, "6200000000000005"),
+    card(CardType::Maestro, "6759649826438453"),
+    card(CardType::Maestro, "6304000000000000"),
+    card(CardType::Mir, "2201382000000013"),
+    card(CardType::Elo, "5066991111111118"),
+    card(CardType::Hipercard, "6062828888666688"),
+    card(CardType::Troy, "9792030394440796"),
+];
+
+const fn card(card_type: CardType, number: &'static str) -> TestCard {
+    TestCard { card_type, number }
+}
+
+/// Returns the sandbox numbers of a network
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::testing::test_cards;
+/// use validator_rs::credit_card::{is_valid_credit_card, CardType};
+///
+/// for number in test_cards(CardType::Amex) {
+///     assert!(is_valid_credit_card(number));
+/// }
+/// ```
+pub fn test_cards(card_type: CardType) -> impl Iterator<Item = &'static str> {
+    TEST_CARDS
+        .iter()
+        .filter(move |card| card.card_type == card_type)
+        .map(|card| card.number)
+}
+
+/// Deterministic generator of Luhn-valid card numbers
+///
+/// The same seed always produces the same sequence of numbers.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::credit_card::testing::PanGenerator;
+/// use validator_rs::credit_card::{get_card_type, is_valid_credit_card_strict, CardType};
+///
+/// let mut generator = PanGenerator::new(42);
+/// let number = generator.generate(CardType::MasterCard, 16).unwrap();
+/// assert_eq!(get_card_type(&number), CardType::MasterCard);
+/// assert!(is_valid_credit_card_strict(&number));
+///
+/// // Amex numbers are always 15 digits
+/// assert_eq!(generator.generate(CardType::Amex, 16), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PanGenerator {
+    state: u64,
+}
+
+impl PanGenerator {
+    /// Maximum attempts to find a number detected as the requested network
+    const MAX_ATTEMPTS: usize = 1000;
+
+    /// Create a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generates a Luhn-valid number of the given network and length
+    ///
+    /// Returns `None` for [`CardType::Unknown`] or a length the network does
+    /// not issue (see [`CardType::lengths`]).
+    pub fn generate(&mut self, card_type: CardType, length: usize) -> Option<String> {
+        if card_type == CardType::Unknown || !card_type.lengths().contains(&length) {
+            return None;
+        }
+        let ranges: Vec<_> = IIN_RANGES
+            .iter()
+            .filter(|(_, _, range_type)| *range_type == card_type)
+            .collect();
+
+        for _ in 0..Self::MAX_ATTEMPTS {
+            let (start, end, _) = ranges[self.below(ranges.len() as u64) as usize];
+            let low: u64 = start.parse().ok()?;
+            let high: u64 = end.parse().ok()?;
+            let prefix = low + self.below(high - low + 1);
+
+            let mut number = format!("{:0width$}", prefix, width = start.len());
+            while number.len() < length - 1 {
+                number.push(char::from(b'0' + self.below(10) as u8));
+            }
+            number.push(luhn_check_digit(&number)?);
+
+            // Skip numbers that fall into a more specific range of another network
+            if get_card_type(&number) == card_type {
+                return Some(number);
+            }
+        }
+        None
+    }
+
+    /// Returns a pseudo-random number below `bound` (SplitMix64)
+    fn below(&mut self, bound: u64) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        (z ^ (z >> 31)) % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credit_card::{is_credit_card, CardOptions};
+
+    const NETWORKS: [CardType; 14] = [
+        CardType::Visa,
+        CardType::MasterCard,
+        CardType::Amex,
+        CardType::Discover,
+        CardType::Jcb,
+        CardType::DinersClub,
+        CardType::UnionPay,
+        CardType::Maestro,
+        CardType::Mir,
+        CardType::RuPay,
+        CardType::Elo,
+        CardType::Hipercard,
+        CardType::Troy,
+        CardType::Verve,
+    ];
+
+    #[test]
+    fn test_test_cards() {
+        let options = CardOptions::new().require_network_length(true);
+        for card in TEST_CARDS {
+            let info = is_credit_card(card.number, Some(options.clone())).unwrap();
+            assert_eq!(info.card_type(), card.card_type, "{}", card.number);
+        }
+        assert_eq!(test_cards(CardType::Jcb).count(), 2);
+        assert_eq!(test_cards(CardType::Unknown).count(), 0);
+    }
+
+    #[test]
+    fn test_generator_all_networks() {
+        let mut generator = PanGenerator::new(7);
+        let options = CardOptions::new().require_network_length(true);
+        for card_type in NETWORKS {
+            for &length in card_type.lengths() {
+                for _ in 0..20 {
+                    let number = generator.generate(card_type, length).unwrap();
+                    assert_eq!(number.len(), length);
+                    let info = is_credit_card(&number, Some(options.clone())).unwrap();
+                    assert_eq!(info.card_type(), card_type, "{}", number);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generator_is_deterministic() {
+        let first = PanGenerator::new(1).generate(CardType::Visa, 16);
+        assert_eq!(first, PanGenerator::new(1).generate(CardType::Visa, 16));
+        assert_ne!(first, PanGenerator::new(2).generate(CardType::Visa, 16));
+
+        let mut generator = PanGenerator::new(1);
+        assert_eq!(generator.generate(CardType::Unknown, 16), None);
+        assert_eq!(generator.generate(CardType::Visa, 15), None);
+    }
+}
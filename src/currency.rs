@@ -3,6 +3,8 @@
 //! This module provides validation for currency strings with extensive
 //! customization options for different currency formats worldwide.
 
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
 use regex::Regex;
 
 /// Options for currency validation
@@ -195,7 +197,7 @@ fn build_currency_regex(options: &CurrencyOptions) -> Result<Regex, regex::Error
     let whole_dollar_amount_with_sep =
         format!(r"[1-9]\d{{0,2}}({}\d{{3}})*", escaped_thousands_sep);
 
-    let valid_whole_dollar_amounts = vec![
+    let valid_whole_dollar_amounts = [
         "0",
         whole_dollar_amount_without_sep,
         &whole_dollar_amount_with_sep,
@@ -289,18 +291,20 @@ fn validate_currency_manual(value: &str, options: &CurrencyOptions) -> bool {
 
     // Check for invalid patterns with spaces
     // "$ " (symbol followed by space when not allowed)
-    if !options.allow_space_after_symbol && !options.allow_negative_sign_placeholder {
-        if value.contains(&format!("{} ", options.symbol)) {
-            return false;
-        }
+    if !options.allow_space_after_symbol
+        && !options.allow_negative_sign_placeholder
+        && value.contains(&format!("{} ", options.symbol))
+    {
+        return false;
     }
 
     // Check for "SYMBOL -" pattern (space between symbol and negative)
     // This is invalid with allow_negative_sign_placeholder but valid with allow_space_after_symbol
-    if options.allow_negative_sign_placeholder && !options.allow_space_after_symbol {
-        if value.contains(&format!("{} -", options.symbol)) {
-            return false;
-        }
+    if options.allow_negative_sign_placeholder
+        && !options.allow_space_after_symbol
+        && value.contains(&format!("{} -", options.symbol))
+    {
+        return false;
     }
 
     // Check specific invalid patterns
@@ -350,6 +354,45 @@ pub fn is_currency(value: &str, options: Option<CurrencyOptions>) -> bool {
     }
 }
 
+/// Currency validator implementing [`Validator`]
+///
+/// Fails with code `currency.invalid` and a `symbol` parameter.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::currency::{CurrencyOptions, CurrencyValidator};
+/// use validator_rs::validator::Validator;
+///
+/// let validator = CurrencyValidator::new(CurrencyOptions::new().symbol("€"));
+/// assert!(validator.validate("€10.50", "price").is_ok());
+/// assert!(validator.validate("$10.50", "price").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyValidator {
+    /// Currency format options
+    pub options: CurrencyOptions,
+}
+
+impl CurrencyValidator {
+    /// Create a new CurrencyValidator with the given options
+    pub fn new(options: CurrencyOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for CurrencyValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        if is_currency(value.as_ref(), Some(self.options.clone())) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(field, "Invalid currency amount")
+                .with_code("currency.invalid")
+                .with_param("symbol", self.options.symbol.as_str()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1505,4 +1548,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_currency_validator() {
+        let validator = CurrencyValidator::default();
+        assert!(validator.validate("$10,123.45", "price").is_ok());
+        let error = validator.validate("$ 32.50", "price").unwrap_err();
+        assert_eq!(error.code, "currency.invalid");
+
+        let validator = CurrencyValidator::new(CurrencyOptions::new().require_symbol(true));
+        assert!(validator.validate("$10.00", "price").is_ok());
+        assert!(validator.validate("10.00", "price").is_err());
+    }
 }
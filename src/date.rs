@@ -1,5 +1,7 @@
 //! Date and time validation functions
 
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
 use regex::Regex;
 use std::sync::OnceLock;
 
@@ -31,32 +33,38 @@ fn get_datetime_regex() -> &'static Regex {
 /// assert!(!is_valid_date("31-12-2023"));
 /// ```
 pub fn is_valid_date(date: &str) -> bool {
+    parse_date(date).is_some()
+}
+
+/// Parses an ISO 8601 date (YYYY-MM-DD) into its year, month and day
+pub(crate) fn parse_date(date: &str) -> Option<(i32, u32, u32)> {
     if !get_date_regex().is_match(date) {
-        return false;
+        return None;
     }
 
     let parts: Vec<&str> = date.split('-').collect();
     if parts.len() != 3 {
-        return false;
+        return None;
     }
 
-    let year = parts[0].parse::<i32>().ok();
-    let month = parts[1].parse::<u32>().ok();
-    let day = parts[2].parse::<u32>().ok();
+    let year = parts[0].parse::<i32>().ok()?;
+    let month = parts[1].parse::<u32>().ok()?;
+    let day = parts[2].parse::<u32>().ok()?;
 
-    match (year, month, day) {
-        (Some(y), Some(m), Some(d)) => is_valid_date_parts(y, m, d),
-        _ => false,
-    }
+    is_valid_date_parts(year, month, day).then_some((year, month, day))
 }
 
 /// Validates if year, month, and day form a valid date
 fn is_valid_date_parts(year: i32, month: u32, day: u32) -> bool {
-    if !(1..=12).contains(&month) || day < 1 {
-        return false;
+    match days_in_month(year, month) {
+        Some(max_day) => (1..=max_day).contains(&day),
+        None => false,
     }
+}
 
-    let max_day = match month {
+/// Returns the number of days in a month, or `None` if the month is not 1-12
+pub(crate) fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let days = match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
         2 => {
@@ -66,10 +74,10 @@ fn is_valid_date_parts(year: i32, month: u32, day: u32) -> bool {
                 28
             }
         }
-        _ => return false,
+        _ => return None,
     };
 
-    day <= max_day
+    Some(days)
 }
 
 /// Checks if a year is a leap year
@@ -99,6 +107,75 @@ pub fn is_valid_time(time: &str) -> bool {
     }
 }
 
+/// Format accepted by a [`DateValidator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateFormat {
+    /// ISO 8601 date (YYYY-MM-DD)
+    #[default]
+    Date,
+    /// ISO 8601 datetime
+    DateTime,
+    /// Time in HH:MM:SS format
+    Time,
+}
+
+/// Configurable date/time validator implementing [`Validator`]
+///
+/// Fails with code `date.invalid`, `datetime.invalid` or `time.invalid`
+/// depending on the format.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::date::{DateFormat, DateValidator};
+/// use validator_rs::validator::Validator;
+///
+/// assert!(DateValidator::new().validate("2024-02-29", "birthday").is_ok());
+///
+/// let validator = DateValidator::new().format(DateFormat::Time);
+/// assert!(validator.validate("12:30:45", "start").is_ok());
+/// assert!(validator.validate("25:00:00", "start").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DateValidator {
+    /// Format the value must be in
+    pub format: DateFormat,
+}
+
+impl DateValidator {
+    /// Create a new DateValidator accepting ISO 8601 dates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the accepted format
+    pub fn format(mut self, format: DateFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for DateValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let value = value.as_ref();
+        let (valid, code, message) = match self.format {
+            DateFormat::Date => (is_valid_date(value), "date.invalid", "Invalid date"),
+            DateFormat::DateTime => (
+                is_valid_datetime(value),
+                "datetime.invalid",
+                "Invalid datetime",
+            ),
+            DateFormat::Time => (is_valid_time(value), "time.invalid", "Invalid time"),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ValidationError::new(field, message).with_code(code))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,5 +232,16 @@ mod tests {
         assert!(!is_valid_time("12:30:60"));
         assert!(!is_valid_time("12:30"));
     }
+
+    #[test]
+    fn test_date_validator() {
+        assert!(DateValidator::new().validate("2023-12-31", "date").is_ok());
+        assert!(DateValidator::new().validate("2023-02-29", "date").is_err());
+
+        let validator = DateValidator::new().format(DateFormat::DateTime);
+        assert!(validator.validate("2023-12-31T23:59:59Z", "created_at").is_ok());
+        let error = validator.validate("2023-12-31", "created_at").unwrap_err();
+        assert_eq!(error.code, "datetime.invalid");
+    }
 }
 
@@ -1,9 +1,31 @@
 //! Email validation functions
+//!
+//! Internationalized addresses (RFC 6531) with UTF-8 local parts and IDNA
+//! domains such as `josé@exämple.de` are accepted; see [`crate::idna`] for
+//! converting domains to their ASCII `xn--` form.
 
+use crate::idna;
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
 use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
 use std::sync::OnceLock;
 
+/// Maximum length of an email address in octets (RFC 5321)
+const MAX_EMAIL_LEN: usize = 254;
+/// Maximum length of the local part in octets (RFC 5321)
+const MAX_LOCAL_PART_LEN: usize = 64;
+/// Maximum length of a domain label in octets (RFC 1035)
+const MAX_LABEL_LEN: usize = 63;
+
 static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
+static LOCAL_PART_REGEX: OnceLock<Regex> = OnceLock::new();
+static DOMAIN_REGEX: OnceLock<Regex> = OnceLock::new();
+static DISPOSABLE_DOMAINS: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Bundled list of known disposable email providers
+const BUNDLED_DISPOSABLE_DOMAINS: &str = include_str!("../data/disposable_domains.txt");
 
 fn get_email_regex() -> &'static Regex {
     EMAIL_REGEX.get_or_init(|| {
@@ -12,6 +34,20 @@ fn get_email_regex() -> &'static Regex {
     })
 }
 
+fn get_local_part_regex() -> &'static Regex {
+    LOCAL_PART_REGEX.get_or_init(|| {
+        Regex::new(r"^(?:[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]|[^\x00-\x7F\p{Cc}\p{Z}])+$")
+            .expect("Failed to compile email local part regex")
+    })
+}
+
+fn get_domain_regex() -> &'static Regex {
+    DOMAIN_REGEX.get_or_init(|| {
+        Regex::new(r"^[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$")
+            .expect("Failed to compile email domain regex")
+    })
+}
+
 /// Validates if a string is a valid email address
 ///
 /// # Examples
@@ -23,28 +59,1066 @@ fn get_email_regex() -> &'static Regex {
 /// assert!(is_valid_email("test.email+tag@domain.co.uk"));
 /// assert!(!is_valid_email("invalid.email"));
 /// assert!(!is_valid_email("@example.com"));
+///
+/// // Internationalized addresses
+/// assert!(is_valid_email("用户@例子.广告"));
+/// assert!(is_valid_email("josé@exämple.de"));
 /// ```
 pub fn is_valid_email(email: &str) -> bool {
-    if email.is_empty() || email.len() > 254 {
+    if email.is_empty() || email.len() > MAX_EMAIL_LEN {
         return false;
     }
 
-    get_email_regex().is_match(email)
+    if !email.is_ascii() {
+        return EmailAddress::parse(email).is_ok();
+    }
+
+    let local_part_len = email.rfind('@').unwrap_or(email.len());
+    local_part_len <= MAX_LOCAL_PART_LEN && get_email_regex().is_match(email)
 }
 
 /// Validates if a string is a valid email address and checks domain
 pub fn is_valid_email_with_domain(email: &str, allowed_domains: &[&str]) -> bool {
-    if !is_valid_email(email) {
-        return false;
+    EmailAddress::parse(email).is_ok_and(|email| allowed_domains.contains(&email.domain()))
+}
+
+/// Options for [`is_email`]
+///
+/// Mirrors the options of validator.js's `isEmail`. The defaults accept quoted
+/// and UTF-8 local parts and require a top-level domain, but reject display
+/// names and IP address domains.
+#[derive(Debug, Clone)]
+pub struct EmailOptions {
+    /// Allow quoted local parts such as `"john doe"@example.com`
+    pub allow_quoted_local: bool,
+    /// Allow IP address domains such as `user@[192.168.0.1]` or `user@[IPv6:::1]`
+    pub allow_ip_domain: bool,
+    /// Require the domain to end in a top-level domain
+    pub require_tld: bool,
+    /// Allow a display name such as `John Doe <john@example.com>`
+    pub allow_display_name: bool,
+    /// Allow non-ASCII characters in the local part (RFC 6531)
+    pub allow_utf8_local_part: bool,
+    /// Characters rejected in the local part
+    pub blacklisted_chars: String,
+}
+
+impl Default for EmailOptions {
+    fn default() -> Self {
+        Self {
+            allow_quoted_local: true,
+            allow_ip_domain: false,
+            require_tld: true,
+            allow_display_name: false,
+            allow_utf8_local_part: true,
+            blacklisted_chars: String::new(),
+        }
+    }
+}
+
+impl EmailOptions {
+    /// Create a new EmailOptions with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether quoted local parts are allowed
+    pub fn allow_quoted_local(mut self, allow: bool) -> Self {
+        self.allow_quoted_local = allow;
+        self
     }
 
-    if let Some(domain) = email.split('@').nth(1) {
-        allowed_domains.iter().any(|&d| domain == d)
+    /// Set whether IP address domains are allowed
+    pub fn allow_ip_domain(mut self, allow: bool) -> Self {
+        self.allow_ip_domain = allow;
+        self
+    }
+
+    /// Set whether a top-level domain is required
+    pub fn require_tld(mut self, require: bool) -> Self {
+        self.require_tld = require;
+        self
+    }
+
+    /// Set whether a display name is allowed
+    pub fn allow_display_name(mut self, allow: bool) -> Self {
+        self.allow_display_name = allow;
+        self
+    }
+
+    /// Set whether non-ASCII characters are allowed in the local part
+    pub fn allow_utf8_local_part(mut self, allow: bool) -> Self {
+        self.allow_utf8_local_part = allow;
+        self
+    }
+
+    /// Set the characters rejected in the local part
+    pub fn blacklisted_chars(mut self, chars: impl Into<String>) -> Self {
+        self.blacklisted_chars = chars.into();
+        self
+    }
+}
+
+/// Validates an email address against configurable rules
+///
+/// Enforces the RFC 5321 limits of 254 octets per address, 64 octets per
+/// local part and 63 octets per domain label. Uses the default
+/// [`EmailOptions`] if `None` is provided.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::email::{is_email, EmailOptions};
+///
+/// assert!(is_email("user@example.com", None));
+/// assert!(is_email("\"john doe\"@example.com", None));
+/// assert!(!is_email("user@localhost", None));
+///
+/// let options = EmailOptions::new()
+///     .allow_display_name(true)
+///     .allow_ip_domain(true);
+/// assert!(is_email("John Doe <john@example.com>", Some(options.clone())));
+/// assert!(is_email("user@[192.168.0.1]", Some(options)));
+/// ```
+pub fn is_email(value: &str, options: Option<EmailOptions>) -> bool {
+    let opts = options.unwrap_or_default();
+
+    let email = if opts.allow_display_name {
+        match split_display_name(value) {
+            Some(email) => email,
+            None => return false,
+        }
     } else {
-        false
+        value
+    };
+
+    if email.is_empty() || email.len() > MAX_EMAIL_LEN {
+        return false;
+    }
+
+    let Some((local_part, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+
+    if local_part.len() > MAX_LOCAL_PART_LEN
+        || local_part
+            .chars()
+            .any(|c| opts.blacklisted_chars.contains(c))
+    {
+        return false;
+    }
+
+    is_valid_local_part(local_part, &opts) && is_valid_email_domain(domain, &opts)
+}
+
+/// Extracts the address from `Display Name <address>`, or returns the input
+/// unchanged if it has no display name
+fn split_display_name(value: &str) -> Option<&str> {
+    let Some(inner) = value.strip_suffix('>') else {
+        return Some(value);
+    };
+    let (name, email) = inner.rsplit_once('<')?;
+    let name = name.trim();
+
+    if name.chars().any(char::is_control) {
+        return None;
+    }
+
+    // Names with special characters must be quoted
+    let valid_name = match name.strip_prefix('"').and_then(|n| n.strip_suffix('"')) {
+        Some(quoted) => !quoted.replace("\\\"", "").contains('"'),
+        None => !name.contains(['"', '.', ';', '<', '>', '@']),
+    };
+
+    valid_name.then_some(email)
+}
+
+fn is_atext(c: char, opts: &EmailOptions) -> bool {
+    c.is_ascii_alphanumeric()
+        || "!#$%&'*+/=?^_`{|}~-".contains(c)
+        || (opts.allow_utf8_local_part && !c.is_ascii() && !c.is_control())
+}
+
+fn is_valid_local_part(local_part: &str, opts: &EmailOptions) -> bool {
+    if let Some(quoted) = local_part
+        .strip_prefix('"')
+        .and_then(|l| l.strip_suffix('"'))
+        .filter(|_| local_part.len() >= 2)
+    {
+        if !opts.allow_quoted_local {
+            return false;
+        }
+
+        // qtext or quoted-pair (RFC 5322 section 3.2.4)
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            let valid = match c {
+                '\\' => chars
+                    .next()
+                    .is_some_and(|e| e == ' ' || e.is_ascii_graphic()),
+                '"' => false,
+                c if c.is_ascii() => c == ' ' || c.is_ascii_graphic(),
+                c => opts.allow_utf8_local_part && !c.is_control(),
+            };
+            if !valid {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    // dot-atom (RFC 5322 section 3.2.3)
+    local_part
+        .split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(|c| is_atext(c, opts)))
+}
+
+fn is_valid_email_domain(domain: &str, opts: &EmailOptions) -> bool {
+    if let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+        return opts.allow_ip_domain
+            && match literal.strip_prefix("IPv6:") {
+                Some(ipv6) => ipv6.parse::<std::net::Ipv6Addr>().is_ok(),
+                None => literal.parse::<std::net::Ipv4Addr>().is_ok(),
+            };
+    }
+
+    if domain.parse::<std::net::Ipv4Addr>().is_ok() {
+        return opts.allow_ip_domain;
+    }
+
+    match idna::to_ascii(domain) {
+        Ok(ascii) => is_valid_hostname(&ascii, opts.require_tld),
+        Err(_) => false,
+    }
+}
+
+/// Validates an ASCII host name, optionally requiring a top-level domain
+fn is_valid_hostname(domain: &str, require_tld: bool) -> bool {
+    if domain.is_empty() || domain.len() > MAX_EMAIL_LEN - 1 {
+        return false;
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let labels_valid = labels.iter().all(|label| {
+        !label.is_empty()
+            && label.len() <= MAX_LABEL_LEN
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+    if !labels_valid {
+        return false;
+    }
+
+    if require_tld {
+        let tld = labels[labels.len() - 1];
+        let valid_tld = tld.len() >= 2
+            && (tld.chars().all(|c| c.is_ascii_alphabetic())
+                || tld.to_ascii_lowercase().starts_with("xn--"));
+        return labels.len() >= 2 && valid_tld;
+    }
+
+    true
+}
+
+/// Reason a domain was rejected by a [`DomainPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainPolicyError {
+    /// The domain matches a deny-list pattern
+    Denied {
+        /// The matching deny-list pattern
+        pattern: String,
+    },
+    /// The allow-list is non-empty and the domain matches none of its patterns
+    NotAllowed,
+    /// The domain belongs to a disposable email provider
+    Disposable,
+}
+
+impl std::fmt::Display for DomainPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainPolicyError::Denied { pattern } => {
+                write!(f, "domain is blocked by '{}'", pattern)
+            }
+            DomainPolicyError::NotAllowed => f.write_str("domain is not in the allow-list"),
+            DomainPolicyError::Disposable => f.write_str("domain is a disposable email provider"),
+        }
+    }
+}
+
+impl std::error::Error for DomainPolicyError {}
+
+/// Allow-list, deny-list and disposable-provider rules for email domains
+///
+/// Patterns are either exact domains (`example.com`) or wildcards matching
+/// any subdomain (`*.example.com`, which does not match `example.com`
+/// itself). Matching is case-insensitive, and internationalized domains are
+/// compared in their ASCII form.
+///
+/// Rules are checked in order: deny-list, allow-list, then disposable
+/// providers. Disposable providers are only checked when enabled with
+/// [`DomainPolicy::block_disposable`], using the bundled list unless one is
+/// loaded with [`DomainPolicy::load_disposable_list`].
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::email::{DomainPolicy, DomainPolicyError};
+///
+/// let policy = DomainPolicy::new()
+///     .deny(["*.spam.example"])
+///     .block_disposable(true);
+///
+/// assert!(policy.check_email("user@example.com").is_ok());
+/// assert!(policy.check("mail.spam.example").is_err());
+/// assert_eq!(
+///     policy.check_email("user@mailinator.com"),
+///     Err(DomainPolicyError::Disposable)
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DomainPolicy {
+    /// If non-empty, the domain must match one of these patterns
+    pub allow: Vec<String>,
+    /// The domain must not match any of these patterns
+    pub deny: Vec<String>,
+    /// Reject domains of disposable email providers
+    pub block_disposable: bool,
+    disposable_domains: Option<HashSet<String>>,
+}
+
+impl DomainPolicy {
+    /// Create a policy that accepts every domain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the allow-list patterns
+    pub fn allow<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allow = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the deny-list patterns
+    pub fn deny<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.deny = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set whether disposable email providers are rejected
+    pub fn block_disposable(mut self, block: bool) -> Self {
+        self.block_disposable = block;
+        self
+    }
+
+    /// Replace the disposable provider list
+    pub fn disposable_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.disposable_domains = Some(
+            domains
+                .into_iter()
+                .map(|domain| normalize_domain(domain.as_ref()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Replace the disposable provider list with the contents of a file
+    ///
+    /// The file lists one domain per line; blank lines and lines starting
+    /// with `#` are ignored. Call again to refresh the list. Returns the
+    /// number of domains loaded.
+    pub fn load_disposable_list(&mut self, path: impl AsRef<Path>) -> std::io::Result<usize> {
+        let contents = std::fs::read_to_string(path)?;
+        let domains = parse_domain_list(&contents);
+        let count = domains.len();
+        self.disposable_domains = Some(domains);
+        Ok(count)
+    }
+
+    /// Returns `true` if the domain or one of its parents is a disposable provider
+    pub fn is_disposable(&self, domain: &str) -> bool {
+        let list = self
+            .disposable_domains
+            .as_ref()
+            .unwrap_or_else(|| bundled_disposable_domains());
+        let domain = normalize_domain(domain);
+
+        let mut candidate = domain.as_str();
+        loop {
+            if list.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) => candidate = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Checks a domain against the policy
+    pub fn check(&self, domain: &str) -> Result<(), DomainPolicyError> {
+        let domain = normalize_domain(domain);
+
+        if let Some(pattern) = self
+            .deny
+            .iter()
+            .find(|pattern| domain_matches(&domain, pattern))
+        {
+            return Err(DomainPolicyError::Denied {
+                pattern: pattern.clone(),
+            });
+        }
+
+        if !self.allow.is_empty()
+            && !self
+                .allow
+                .iter()
+                .any(|pattern| domain_matches(&domain, pattern))
+        {
+            return Err(DomainPolicyError::NotAllowed);
+        }
+
+        if self.block_disposable && self.is_disposable(&domain) {
+            return Err(DomainPolicyError::Disposable);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the domain of an email address against the policy
+    pub fn check_email(&self, email: &str) -> Result<(), DomainPolicyError> {
+        self.check(email_domain(email))
+    }
+}
+
+fn bundled_disposable_domains() -> &'static HashSet<String> {
+    DISPOSABLE_DOMAINS.get_or_init(|| parse_domain_list(BUNDLED_DISPOSABLE_DOMAINS))
+}
+
+fn parse_domain_list(contents: &str) -> HashSet<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(normalize_domain)
+        .collect()
+}
+
+/// Lowercases a domain, converting internationalized domains to ASCII
+fn normalize_domain(domain: &str) -> String {
+    let domain = domain.trim().trim_end_matches('.');
+    idna::to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+}
+
+/// Matches a normalized domain against an exact or `*.` wildcard pattern
+fn domain_matches(domain: &str, pattern: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => domain
+            .strip_suffix(&normalize_domain(suffix))
+            .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.')),
+        None => domain == normalize_domain(pattern),
+    }
+}
+
+/// Returns the domain of an address, ignoring any display name brackets
+fn email_domain(email: &str) -> &str {
+    email
+        .trim_end_matches('>')
+        .rsplit_once('@')
+        .map_or("", |(_, domain)| domain)
+}
+
+/// Configurable email validator implementing [`Validator`]
+///
+/// Fails with code `email.invalid`, or `email.domain_not_allowed` (with a
+/// `domain` parameter) when the domain is not in `allowed_domains`. With a
+/// [`DomainPolicy`], also fails with `email.domain_denied` (with a `domain`
+/// parameter) or `email.disposable`.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::email::EmailValidator;
+/// use validator_rs::validator::Validator;
+///
+/// let validator = EmailValidator::new().allowed_domains(["example.com"]);
+/// assert!(validator.validate("user@example.com", "email").is_ok());
+/// assert!(validator.validate("user@other.com", "email").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EmailValidator {
+    /// If non-empty, the email domain must be one of these
+    pub allowed_domains: Vec<String>,
+    /// If set, validate with [`is_email`] instead of [`is_valid_email`]
+    pub options: Option<EmailOptions>,
+    /// If set, the email domain must satisfy this policy
+    pub policy: Option<DomainPolicy>,
+}
+
+impl EmailValidator {
+    /// Create a new EmailValidator accepting any domain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the accepted email domains
+    pub fn allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_domains = domains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Validate with configurable [`EmailOptions`]
+    pub fn options(mut self, options: EmailOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Check the email domain against a [`DomainPolicy`]
+    pub fn policy(mut self, policy: DomainPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for EmailValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let email = value.as_ref();
+        let valid = match &self.options {
+            Some(options) => is_email(email, Some(options.clone())),
+            None => is_valid_email(email),
+        };
+        if !valid {
+            return Err(
+                ValidationError::new(field, "Invalid email format").with_code("email.invalid")
+            );
+        }
+
+        let domain = email_domain(email);
+        if !self.allowed_domains.is_empty()
+            && !self.allowed_domains.iter().any(|allowed| allowed == domain)
+        {
+            return Err(ValidationError::new(field, "Email domain is not allowed")
+                .with_code("email.domain_not_allowed")
+                .with_param("domain", domain));
+        }
+
+        if let Some(policy) = &self.policy {
+            match policy.check(domain) {
+                Ok(()) => {}
+                Err(DomainPolicyError::Disposable) => {
+                    return Err(ValidationError::new(
+                        field,
+                        "Disposable email addresses are not allowed",
+                    )
+                    .with_code("email.disposable"));
+                }
+                Err(DomainPolicyError::NotAllowed) => {
+                    return Err(ValidationError::new(field, "Email domain is not allowed")
+                        .with_code("email.domain_not_allowed")
+                        .with_param("domain", domain));
+                }
+                Err(DomainPolicyError::Denied { .. }) => {
+                    return Err(ValidationError::new(field, "Email domain is blocked")
+                        .with_code("email.domain_denied")
+                        .with_param("domain", domain));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reason an email address failed to parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailError {
+    /// The input is empty
+    Empty,
+    /// The address exceeds 254 characters
+    TooLong,
+    /// The address contains no `@`
+    MissingAt,
+    /// Nothing precedes the `@`
+    EmptyLocalPart,
+    /// The local part exceeds 64 octets
+    LocalPartTooLong,
+    /// Nothing follows the `@`
+    EmptyDomain,
+    /// The local part contains invalid characters
+    InvalidLocalPart,
+    /// The domain is not a valid host name or internationalized domain
+    InvalidDomain,
+}
+
+impl std::fmt::Display for EmailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            EmailError::Empty => "email address is empty",
+            EmailError::TooLong => "email address exceeds 254 characters",
+            EmailError::MissingAt => "email address is missing '@'",
+            EmailError::EmptyLocalPart => "email address has an empty local part",
+            EmailError::LocalPartTooLong => "email local part exceeds 64 characters",
+            EmailError::EmptyDomain => "email address has an empty domain",
+            EmailError::InvalidLocalPart => "email address has an invalid local part",
+            EmailError::InvalidDomain => "email address has an invalid domain",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+/// Options for [`EmailAddress::normalize`]
+///
+/// The default only lowercases the domain, which never changes where mail is
+/// delivered. The other options are provider-specific and meant for
+/// deduplicating accounts rather than for storing the address.
+#[derive(Debug, Clone)]
+pub struct NormalizeOptions {
+    /// Lowercase the domain (default: true)
+    pub lowercase_domain: bool,
+    /// Lowercase the local part (default: false)
+    pub lowercase_local_part: bool,
+    /// Remove the `+tag` suffix from the local part (default: false)
+    pub strip_tag: bool,
+    /// Remove dots from Gmail local parts (default: false)
+    pub remove_gmail_dots: bool,
+    /// Convert internationalized domains to their `xn--` form (default: false)
+    pub domain_to_ascii: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            lowercase_domain: true,
+            lowercase_local_part: false,
+            strip_tag: false,
+            remove_gmail_dots: false,
+            domain_to_ascii: false,
+        }
+    }
+}
+
+impl NormalizeOptions {
+    /// Create default normalization options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to lowercase the domain
+    pub fn lowercase_domain(mut self, lowercase: bool) -> Self {
+        self.lowercase_domain = lowercase;
+        self
+    }
+
+    /// Set whether to lowercase the local part
+    pub fn lowercase_local_part(mut self, lowercase: bool) -> Self {
+        self.lowercase_local_part = lowercase;
+        self
+    }
+
+    /// Set whether to remove `+tag` suffixes
+    pub fn strip_tag(mut self, strip: bool) -> Self {
+        self.strip_tag = strip;
+        self
+    }
+
+    /// Set whether to remove dots from Gmail local parts
+    pub fn remove_gmail_dots(mut self, remove: bool) -> Self {
+        self.remove_gmail_dots = remove;
+        self
+    }
+
+    /// Set whether to convert internationalized domains to their `xn--` form
+    pub fn domain_to_ascii(mut self, to_ascii: bool) -> Self {
+        self.domain_to_ascii = to_ascii;
+        self
+    }
+}
+
+/// Domains whose local parts ignore dots
+const GMAIL_DOMAINS: &[&str] = &["gmail.com", "googlemail.com"];
+
+/// A parsed email address
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::email::{EmailAddress, EmailError, NormalizeOptions};
+///
+/// let email = EmailAddress::parse("John.Doe+news@GMail.com").unwrap();
+/// assert_eq!(email.local_part(), "John.Doe+news");
+/// assert_eq!(email.domain(), "GMail.com");
+/// assert_eq!(email.tag(), Some("news"));
+///
+/// let options = NormalizeOptions::new()
+///     .lowercase_local_part(true)
+///     .strip_tag(true)
+///     .remove_gmail_dots(true);
+/// assert_eq!(email.normalize(&options).to_string(), "johndoe@gmail.com");
+///
+/// assert_eq!(EmailAddress::parse("user.example.com"), Err(EmailError::MissingAt));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailAddress {
+    local_part: String,
+    domain: String,
+}
+
+impl EmailAddress {
+    /// Parses an email address, returning the reason it is invalid on failure
+    pub fn parse(email: &str) -> Result<Self, EmailError> {
+        if email.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        if email.len() > MAX_EMAIL_LEN {
+            return Err(EmailError::TooLong);
+        }
+
+        let (local_part, domain) = email.rsplit_once('@').ok_or(EmailError::MissingAt)?;
+        if local_part.is_empty() {
+            return Err(EmailError::EmptyLocalPart);
+        }
+        if local_part.len() > MAX_LOCAL_PART_LEN {
+            return Err(EmailError::LocalPartTooLong);
+        }
+        if domain.is_empty() {
+            return Err(EmailError::EmptyDomain);
+        }
+        if !get_local_part_regex().is_match(local_part) {
+            return Err(EmailError::InvalidLocalPart);
+        }
+        let ascii_domain = idna::to_ascii(domain).map_err(|_| EmailError::InvalidDomain)?;
+        if !get_domain_regex().is_match(&ascii_domain) {
+            return Err(EmailError::InvalidDomain);
+        }
+
+        Ok(EmailAddress {
+            local_part: local_part.to_string(),
+            domain: domain.to_string(),
+        })
+    }
+
+    /// Returns the part before the `@`
+    pub fn local_part(&self) -> &str {
+        &self.local_part
+    }
+
+    /// Returns the part after the `@`
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Returns the domain in its ASCII form, with Unicode labels as `xn--` Punycode
+    pub fn ascii_domain(&self) -> String {
+        idna::to_ascii(&self.domain).expect("domain validated by parse")
+    }
+
+    /// Returns the domain in its Unicode form, decoding `xn--` labels
+    pub fn unicode_domain(&self) -> String {
+        idna::to_unicode(&self.domain).expect("domain validated by parse")
+    }
+
+    /// Returns `true` if delivery requires SMTPUTF8 because the local part is not ASCII
+    pub fn requires_smtputf8(&self) -> bool {
+        !self.local_part.is_ascii()
+    }
+
+    /// Returns the sub-address after the first `+` in the local part, if any
+    pub fn tag(&self) -> Option<&str> {
+        self.local_part.split_once('+').map(|(_, tag)| tag)
+    }
+
+    /// Returns a normalized copy of the address
+    pub fn normalize(&self, options: &NormalizeOptions) -> EmailAddress {
+        let mut domain = self.domain.clone();
+        if options.domain_to_ascii {
+            domain = self.ascii_domain();
+        } else if options.lowercase_domain {
+            domain = domain.to_lowercase();
+        }
+
+        let mut local_part = self.local_part.clone();
+        if options.strip_tag {
+            if let Some(index) = local_part.find('+') {
+                local_part.truncate(index);
+            }
+        }
+        if options.remove_gmail_dots
+            && GMAIL_DOMAINS
+                .iter()
+                .any(|gmail| gmail.eq_ignore_ascii_case(&domain))
+        {
+            local_part.retain(|c| c != '.');
+        }
+        if options.lowercase_local_part {
+            local_part = local_part.to_ascii_lowercase();
+        }
+
+        EmailAddress { local_part, domain }
     }
 }
 
+impl std::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.local_part, self.domain)
+    }
+}
+
+impl std::str::FromStr for EmailAddress {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Popular email providers used by [`EmailSuggester`] by default
+pub const POPULAR_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "yahoo.com",
+    "hotmail.com",
+    "outlook.com",
+    "aol.com",
+    "icloud.com",
+    "live.com",
+    "msn.com",
+    "me.com",
+    "mac.com",
+    "ymail.com",
+    "googlemail.com",
+    "protonmail.com",
+    "proton.me",
+    "zoho.com",
+    "mail.com",
+    "gmx.com",
+    "gmx.de",
+    "web.de",
+    "yandex.ru",
+    "mail.ru",
+    "qq.com",
+    "163.com",
+    "comcast.net",
+    "verizon.net",
+    "att.net",
+    "hotmail.co.uk",
+    "yahoo.co.uk",
+    "yahoo.co.in",
+    "rediffmail.com",
+];
+
+/// Common top-level domains used by [`EmailSuggester`] by default
+pub const POPULAR_TLDS: &[&str] = &[
+    "com", "net", "org", "edu", "gov", "io", "co", "info", "biz", "me", "us", "uk", "co.uk", "de",
+    "fr", "es", "it", "nl", "ru", "in", "co.in", "jp", "cn", "br", "ca", "au", "com.au",
+];
+
+/// Domains shorter than this allow at most one edit in domain suggestions
+const SHORT_DOMAIN_LEN: usize = 8;
+
+/// Suggests corrections for mistyped email domains
+///
+/// The domain is compared against a list of popular providers using edit
+/// distance, counting a transposition of adjacent characters as a single
+/// edit; domains shorter than 8 characters allow at most one edit. If no
+/// provider is close enough, only the top-level domain (the trailing labels,
+/// such as `con` or `co.uj`) is compared against a list of common TLDs.
+/// Suggestions are meant to be shown to the user ("did you mean ...?"), not
+/// applied automatically.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::email::{suggest_email, EmailSuggester};
+///
+/// let suggestion = suggest_email("user@gmial.com").unwrap();
+/// assert_eq!(suggestion.to_string(), "user@gmail.com");
+///
+/// assert_eq!(suggest_email("user@example.con").unwrap().to_string(), "user@example.com");
+/// assert!(suggest_email("user@gmail.com").is_none());
+///
+/// let suggester = EmailSuggester::new().domains(["example-corp.com"]);
+/// assert_eq!(
+///     suggester.suggest("jane@exmaple-corp.com").unwrap().domain(),
+///     "example-corp.com"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmailSuggester {
+    /// Known domains to suggest
+    pub domains: Vec<String>,
+    /// Known top-level domains to suggest when no domain is close enough
+    pub top_level_domains: Vec<String>,
+    /// Maximum edit distance for a domain suggestion (default: 2, capped at 1
+    /// for domains shorter than 8 characters)
+    pub max_distance: usize,
+    /// Maximum edit distance for a top-level domain suggestion (default: 1)
+    pub max_tld_distance: usize,
+}
+
+impl Default for EmailSuggester {
+    fn default() -> Self {
+        Self {
+            domains: POPULAR_DOMAINS.iter().map(|d| d.to_string()).collect(),
+            top_level_domains: POPULAR_TLDS.iter().map(|t| t.to_string()).collect(),
+            max_distance: 2,
+            max_tld_distance: 1,
+        }
+    }
+}
+
+impl EmailSuggester {
+    /// Create a suggester using the popular domain and TLD lists
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the known domains
+    pub fn domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.domains = domains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replace the known top-level domains
+    pub fn top_level_domains<I, S>(mut self, tlds: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.top_level_domains = tlds.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the maximum edit distance for domain suggestions
+    pub fn max_distance(mut self, distance: usize) -> Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// Set the maximum edit distance for top-level domain suggestions
+    pub fn max_tld_distance(mut self, distance: usize) -> Self {
+        self.max_tld_distance = distance;
+        self
+    }
+
+    /// Returns a corrected address if the domain looks like a typo
+    ///
+    /// Returns `None` if the address cannot be parsed, its domain is already
+    /// known, or no known domain or TLD is close enough.
+    pub fn suggest(&self, email: &str) -> Option<EmailAddress> {
+        let address = EmailAddress::parse(email.trim()).ok()?;
+        let domain = address.domain().to_lowercase();
+
+        if self
+            .domains
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(&domain))
+        {
+            return None;
+        }
+
+        // Short domains are only a few edits away from other real domains
+        let max_distance = if domain.len() < SHORT_DOMAIN_LEN {
+            self.max_distance.min(1)
+        } else {
+            self.max_distance
+        };
+
+        let suggested = closest(&domain, &self.domains, max_distance).or_else(|| {
+            if self.top_level_domains.iter().any(|known| {
+                domain
+                    .strip_suffix(&known.to_lowercase())
+                    .is_some_and(|name| name.ends_with('.'))
+            }) {
+                return None;
+            }
+            // Compare each known TLD with as many trailing labels as it has,
+            // preferring closer and then longer matches
+            let labels: Vec<&str> = domain.split('.').collect();
+            self.top_level_domains
+                .iter()
+                .filter_map(|known| {
+                    let known = known.to_lowercase();
+                    let split = labels.len().checked_sub(known.split('.').count())?;
+                    let distance = edit_distance(&labels[split..].join("."), &known);
+                    (split > 0 && distance > 0 && distance <= self.max_tld_distance)
+                        .then_some((distance, split, known))
+                })
+                .min_by_key(|&(distance, split, _)| (distance, split))
+                .map(|(_, split, tld)| format!("{}.{}", labels[..split].join("."), tld))
+        })?;
+
+        Some(EmailAddress {
+            local_part: address.local_part().to_string(),
+            domain: suggested,
+        })
+    }
+}
+
+/// Suggests a correction for a mistyped email domain using the default [`EmailSuggester`]
+pub fn suggest_email(email: &str) -> Option<EmailAddress> {
+    EmailSuggester::new().suggest(email)
+}
+
+/// Returns the first candidate with the smallest non-zero distance within `max`
+fn closest(value: &str, candidates: &[String], max: usize) -> Option<String> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(value, &candidate.to_lowercase()), candidate))
+        .filter(|&(distance, _)| distance > 0 && distance <= max)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate.to_lowercase())
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,4 +1152,370 @@ mod tests {
             &["example.com", "test.com"]
         ));
     }
+
+    #[test]
+    fn test_email_validator() {
+        let validator = EmailValidator::new();
+        assert!(validator.validate("user@example.com", "email").is_ok());
+
+        let error = validator.validate("invalid", "email").unwrap_err();
+        assert_eq!(error.field, "email");
+        assert_eq!(error.code, "email.invalid");
+        assert_eq!(error.message, "Invalid email format");
+
+        let validator = validator.allowed_domains(["example.com"]);
+        assert!(validator.validate("user@example.com", "email").is_ok());
+
+        let error = validator.validate("user@other.com", "email").unwrap_err();
+        assert_eq!(error.code, "email.domain_not_allowed");
+        assert_eq!(error.param("domain").unwrap().to_string(), "other.com");
+
+        let validator = validator.options(EmailOptions::new().allow_display_name(true));
+        assert!(validator
+            .validate("\"Jane\" <\"jane doe\"@example.com>", "email")
+            .is_ok());
+        assert_eq!(
+            validator
+                .validate("Jane <jane@other.com>", "email")
+                .unwrap_err()
+                .code,
+            "email.domain_not_allowed"
+        );
+    }
+
+    #[test]
+    fn test_email_address_parse() {
+        let email = EmailAddress::parse("user+tag@Example.com").unwrap();
+        assert_eq!(email.local_part(), "user+tag");
+        assert_eq!(email.domain(), "Example.com");
+        assert_eq!(email.tag(), Some("tag"));
+        assert_eq!(email.to_string(), "user+tag@Example.com");
+        assert_eq!(EmailAddress::parse("user@example.com").unwrap().tag(), None);
+
+        assert_eq!(EmailAddress::parse(""), Err(EmailError::Empty));
+        assert_eq!(
+            EmailAddress::parse(&format!("{}@example.com", "a".repeat(250))),
+            Err(EmailError::TooLong)
+        );
+        assert_eq!(EmailAddress::parse("invalid"), Err(EmailError::MissingAt));
+        assert_eq!(
+            EmailAddress::parse("@example.com"),
+            Err(EmailError::EmptyLocalPart)
+        );
+        assert_eq!(EmailAddress::parse("user@"), Err(EmailError::EmptyDomain));
+        assert_eq!(
+            EmailAddress::parse("user name@example.com"),
+            Err(EmailError::InvalidLocalPart)
+        );
+        assert_eq!(
+            EmailAddress::parse("user@.com"),
+            Err(EmailError::InvalidDomain)
+        );
+    }
+
+    #[test]
+    fn test_email_address_normalize() {
+        let email = EmailAddress::parse("J.Doe+promo@GoogleMail.com").unwrap();
+        assert_eq!(
+            email.normalize(&NormalizeOptions::new()).to_string(),
+            "J.Doe+promo@googlemail.com"
+        );
+        assert_eq!(
+            email
+                .normalize(
+                    &NormalizeOptions::new()
+                        .strip_tag(true)
+                        .remove_gmail_dots(true)
+                )
+                .to_string(),
+            "JDoe@googlemail.com"
+        );
+
+        // Dots are significant for other providers
+        let email = EmailAddress::parse("j.doe+promo@example.com").unwrap();
+        let options = NormalizeOptions::new()
+            .strip_tag(true)
+            .remove_gmail_dots(true);
+        assert_eq!(email.normalize(&options).to_string(), "j.doe@example.com");
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let local = "a".repeat(64);
+        assert!(is_valid_email(&format!("{}@example.com", local)));
+        assert!(!is_valid_email(&format!("a{}@example.com", local)));
+        assert_eq!(
+            EmailAddress::parse(&format!("a{}@example.com", local)),
+            Err(EmailError::LocalPartTooLong)
+        );
+
+        let label = "b".repeat(63);
+        assert!(is_email(&format!("{}@{}.com", local, label), None));
+        assert!(!is_email(&format!("a{}@example.com", local), None));
+        assert!(!is_email(&format!("user@b{}.com", label), None));
+    }
+
+    #[test]
+    fn test_is_email_defaults() {
+        assert!(is_email("user@example.com", None));
+        assert!(is_email("user+tag@sub.example.co.uk", None));
+        assert!(is_email("\"john doe\"@example.com", None));
+        assert!(is_email("\"john\\\"doe\"@example.com", None));
+        assert!(is_email("josé@example.com", None));
+        assert!(is_email("user@example.xn--p1ai", None));
+
+        assert!(!is_email("", None));
+        assert!(!is_email("user", None));
+        assert!(!is_email("user@localhost", None));
+        assert!(!is_email("user@example.c", None));
+        assert!(!is_email("user..name@example.com", None));
+        assert!(!is_email(".user@example.com", None));
+        assert!(!is_email("\"john\"doe\"@example.com", None));
+        assert!(!is_email("user@-example.com", None));
+        assert!(!is_email("user@[192.168.0.1]", None));
+        assert!(!is_email("John <john@example.com>", None));
+    }
+
+    #[test]
+    fn test_is_email_options() {
+        let strict = EmailOptions::new()
+            .allow_quoted_local(false)
+            .allow_utf8_local_part(false);
+        assert!(!is_email("\"john doe\"@example.com", Some(strict.clone())));
+        assert!(!is_email("josé@example.com", Some(strict)));
+
+        let no_tld = EmailOptions::new().require_tld(false);
+        assert!(is_email("user@localhost", Some(no_tld)));
+
+        let ip = EmailOptions::new().allow_ip_domain(true);
+        assert!(is_email("user@[192.168.0.1]", Some(ip.clone())));
+        assert!(is_email("user@192.168.0.1", Some(ip.clone())));
+        assert!(is_email("user@[IPv6:2001:db8::1]", Some(ip.clone())));
+        assert!(!is_email("user@[300.1.1.1]", Some(ip.clone())));
+        assert!(!is_email("user@[2001:db8::1]", Some(ip)));
+
+        let blacklist = EmailOptions::new().blacklisted_chars("+!");
+        assert!(is_email("user@example.com", Some(blacklist.clone())));
+        assert!(!is_email("user+tag@example.com", Some(blacklist)));
+    }
+
+    #[test]
+    fn test_is_email_display_name() {
+        let options = EmailOptions::new().allow_display_name(true);
+        assert!(is_email(
+            "John Doe <john@example.com>",
+            Some(options.clone())
+        ));
+        assert!(is_email(
+            "\"Doe, John.\" <john@example.com>",
+            Some(options.clone())
+        ));
+        assert!(is_email("<john@example.com>", Some(options.clone())));
+        assert!(is_email("john@example.com", Some(options.clone())));
+
+        assert!(!is_email(
+            "John. Doe <john@example.com>",
+            Some(options.clone())
+        ));
+        assert!(!is_email(
+            "John Doe <john@example.com",
+            Some(options.clone())
+        ));
+        assert!(!is_email("John Doe <invalid>", Some(options)));
+    }
+
+    #[test]
+    fn test_internationalized_emails() {
+        assert!(is_valid_email("用户@例子.广告"));
+        assert!(is_valid_email("josé@exämple.de"));
+        assert!(is_valid_email("user@xn--exmple-cua.de"));
+        assert!(!is_valid_email("jo sé@exämple.de"));
+        assert!(!is_valid_email("josé@exä mple.de"));
+        assert!(!is_valid_email("josé@xn--.de"));
+
+        let email = EmailAddress::parse("用户@例子.广告").unwrap();
+        assert_eq!(email.ascii_domain(), "xn--fsqu00a.xn--4rr70v");
+        assert!(email.requires_smtputf8());
+
+        let email = EmailAddress::parse("user@XN--EXMPLE-CUA.de").unwrap();
+        assert_eq!(email.unicode_domain(), "exämple.de");
+        assert!(!email.requires_smtputf8());
+
+        let email = EmailAddress::parse("josé@EXÄMPLE.de").unwrap();
+        assert_eq!(
+            email.normalize(&NormalizeOptions::new()).to_string(),
+            "josé@exämple.de"
+        );
+        assert_eq!(
+            email
+                .normalize(&NormalizeOptions::new().domain_to_ascii(true))
+                .to_string(),
+            "josé@xn--exmple-cua.de"
+        );
+
+        assert!(is_email("用户@例子.广告", None));
+        assert!(!is_email(
+            "josé@exämple.de",
+            Some(EmailOptions::new().allow_utf8_local_part(false))
+        ));
+        assert!(is_email(
+            "jose@exämple.de",
+            Some(EmailOptions::new().allow_utf8_local_part(false))
+        ));
+    }
+
+    #[test]
+    fn test_domain_policy_patterns() {
+        let policy = DomainPolicy::new()
+            .allow(["example.com", "*.example.org", "exämple.de"])
+            .deny(["blocked.example.org"]);
+
+        assert!(policy.check("example.com").is_ok());
+        assert!(policy.check("EXAMPLE.COM.").is_ok());
+        assert!(policy.check("mail.example.org").is_ok());
+        assert!(policy.check("xn--exmple-cua.de").is_ok());
+        assert_eq!(
+            policy.check("example.org"),
+            Err(DomainPolicyError::NotAllowed)
+        );
+        assert_eq!(
+            policy.check("sub.example.com"),
+            Err(DomainPolicyError::NotAllowed)
+        );
+        assert_eq!(
+            policy.check("badexample.org"),
+            Err(DomainPolicyError::NotAllowed)
+        );
+        assert_eq!(
+            policy.check("blocked.example.org"),
+            Err(DomainPolicyError::Denied {
+                pattern: "blocked.example.org".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_domain_policy_disposable() {
+        let policy = DomainPolicy::new();
+        assert!(policy.check_email("user@mailinator.com").is_ok());
+        assert!(policy.is_disposable("mailinator.com"));
+        assert!(policy.is_disposable("eu.Mailinator.com"));
+        assert!(!policy.is_disposable("example.com"));
+
+        let policy = policy.block_disposable(true);
+        assert_eq!(
+            policy.check_email("user@yopmail.com"),
+            Err(DomainPolicyError::Disposable)
+        );
+        assert!(policy.check_email("user@gmail.com").is_ok());
+
+        let custom = policy.disposable_domains(["throwaway.test"]);
+        assert!(custom.check_email("user@mailinator.com").is_ok());
+        assert!(custom.check_email("user@throwaway.test").is_err());
+    }
+
+    #[test]
+    fn test_domain_policy_load_file() {
+        let path = std::env::temp_dir().join(format!(
+            "validator-rs-disposable-{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "# test list\n\nthrowaway.test\nBURNER.TEST\n").unwrap();
+
+        let mut policy = DomainPolicy::new().block_disposable(true);
+        assert_eq!(policy.load_disposable_list(&path).unwrap(), 2);
+        assert!(policy.check("burner.test").is_err());
+        assert!(policy.check("mailinator.com").is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(policy.load_disposable_list(&path).is_err());
+    }
+
+    #[test]
+    fn test_email_validator_policy() {
+        let validator = EmailValidator::new().policy(
+            DomainPolicy::new()
+                .deny(["*.blocked.test"])
+                .block_disposable(true),
+        );
+        assert!(validator.validate("user@example.com", "email").is_ok());
+
+        let code = |email| validator.validate(email, "email").unwrap_err().code;
+        assert_eq!(code("user@mx.blocked.test"), "email.domain_denied");
+        assert_eq!(code("user@guerrillamail.com"), "email.disposable");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("gmail.com", "gmail.com"), 0);
+        assert_eq!(edit_distance("gmial.com", "gmail.com"), 1);
+        assert_eq!(edit_distance("gmal.com", "gmail.com"), 1);
+        assert_eq!(edit_distance("hotmaill.com", "hotmail.com"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_suggest_email() {
+        let suggest = |email| suggest_email(email).map(|e| e.to_string());
+        assert_eq!(suggest("user@gmial.com").as_deref(), Some("user@gmail.com"));
+        assert_eq!(suggest("user@yahoo.con").as_deref(), Some("user@yahoo.com"));
+        assert_eq!(
+            suggest("User@Hotmal.com").as_deref(),
+            Some("User@hotmail.com")
+        );
+        assert_eq!(
+            suggest("user@example.cmo").as_deref(),
+            Some("user@example.com")
+        );
+        assert_eq!(
+            suggest("user@company.co.uj").as_deref(),
+            Some("user@company.co.uk")
+        );
+
+        assert_eq!(suggest("user@gmail.com"), None);
+        assert_eq!(suggest("user@ymail.com"), None);
+        assert_eq!(suggest("user@example.com"), None);
+        assert_eq!(suggest("user@example.xyz"), None);
+        assert_eq!(suggest("invalid"), None);
+    }
+
+    #[test]
+    fn test_suggest_email_subdomains() {
+        let suggest = |email| suggest_email(email).map(|e| e.to_string());
+        assert_eq!(
+            suggest("user@mail.example.con").as_deref(),
+            Some("user@mail.example.com")
+        );
+        assert_eq!(
+            suggest("user@mail.company.co.uj").as_deref(),
+            Some("user@mail.company.co.uk")
+        );
+        assert_eq!(suggest("user@mail.example.co.uk"), None);
+    }
+
+    #[test]
+    fn test_suggest_email_short_domains() {
+        let suggest = |email| suggest_email(email).map(|e| e.to_string());
+        assert_eq!(suggest("user@hp.com"), None);
+        assert_eq!(suggest("user@ibm.com"), None);
+        assert_eq!(suggest("user@qq.cmo").as_deref(), Some("user@qq.com"));
+        assert_eq!(suggest("user@gmx.dr").as_deref(), Some("user@gmx.de"));
+    }
+
+    #[test]
+    fn test_email_suggester_options() {
+        let suggester = EmailSuggester::new()
+            .domains(["corp.example"])
+            .top_level_domains(["example"])
+            .max_distance(1);
+        assert_eq!(
+            suggester.suggest("a@crop.example").unwrap().domain(),
+            "corp.example"
+        );
+        assert!(suggester.suggest("a@gmial.com").is_none());
+        assert_eq!(
+            suggester.suggest("a@other.exmaple").unwrap().domain(),
+            "other.example"
+        );
+    }
 }
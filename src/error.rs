@@ -0,0 +1,771 @@
+//! Validation error types
+//!
+//! This module provides [`ValidationError`] for a single failure and
+//! [`ValidationErrors`] for collecting every failure of a nested value, keyed
+//! by a [`FieldPath`] such as `address.lines[2]` or `items[0].price`.
+//!
+//! Every error produced by the library carries a stable machine-readable
+//! `code` (e.g. `email.invalid`, `length.too_short`) and typed parameters
+//! (e.g. `min`, `max`, `actual`, `locale`), so clients can render their own
+//! messages instead of relying on the English `message`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::validator::Validate;
+
+/// Common result type used across validators
+pub type ValidationResult = Result<(), ValidationError>;
+
+/// Typed value of a [`ValidationError`] parameter
+///
+/// Floats compare equal when both are NaN, so `ParamValue` (and
+/// [`ValidationError`]) can implement [`Eq`].
+#[derive(Debug, Clone)]
+pub enum ParamValue {
+    /// Signed integer
+    Int(i64),
+    /// Unsigned integer
+    UInt(u64),
+    /// Floating point number
+    Float(f64),
+    /// Boolean
+    Bool(bool),
+    /// String
+    Str(String),
+}
+
+impl PartialEq for ParamValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ParamValue::Int(a), ParamValue::Int(b)) => a == b,
+            (ParamValue::UInt(a), ParamValue::UInt(b)) => a == b,
+            (ParamValue::Float(a), ParamValue::Float(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (ParamValue::Bool(a), ParamValue::Bool(b)) => a == b,
+            (ParamValue::Str(a), ParamValue::Str(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ParamValue {}
+
+impl fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Int(v) => write!(f, "{}", v),
+            ParamValue::UInt(v) => write!(f, "{}", v),
+            ParamValue::Float(v) => write!(f, "{}", v),
+            ParamValue::Bool(v) => write!(f, "{}", v),
+            ParamValue::Str(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+macro_rules! impl_param_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for ParamValue {
+                fn from(value: $source) -> Self {
+                    ParamValue::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_param_from!(Int, i64, i8, i16, i32, i64, isize);
+impl_param_from!(UInt, u64, u8, u16, u32, u64, usize);
+impl_param_from!(Float, f64, f32, f64);
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::Str(value)
+    }
+}
+
+/// Error type for validation failures
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::{ParamValue, ValidationError};
+///
+/// let error = ValidationError::new("password", "Must be at least 8 characters long")
+///     .with_code("length.too_short")
+///     .with_param("min", 8usize)
+///     .with_param("actual", 5usize);
+///
+/// assert_eq!(error.code, "length.too_short");
+/// assert_eq!(error.param("min"), Some(&ParamValue::UInt(8)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub field: String,
+    /// Stable machine-readable error code (e.g. `email.invalid`)
+    pub code: String,
+    pub message: String,
+    /// Parameters describing the failure (e.g. `min`, `max`, `actual`)
+    pub params: BTreeMap<String, ParamValue>,
+}
+
+impl ValidationError {
+    /// Create an error with the generic `invalid` code and no parameters
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code: "invalid".to_string(),
+            message: message.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Set the error code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    /// Add a parameter
+    pub fn with_param(mut self, name: impl Into<String>, value: impl Into<ParamValue>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the value of a parameter
+    pub fn param(&self, name: &str) -> Option<&ParamValue> {
+        self.params.get(name)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Validation error for '{}': {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A single segment of a [`FieldPath`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    /// A struct field (e.g. `address`)
+    Field(String),
+    /// A position in a sequence (e.g. `[2]`)
+    Index(usize),
+    /// A key in a map (e.g. `[en]`)
+    Key(String),
+}
+
+/// Path to a value inside a nested structure
+///
+/// Paths are displayed as `address.lines[2]` and can be parsed back from that
+/// form with `From<&str>`. Map keys that would read as an index or contain
+/// `.`, `[`, `]`, `"` or `\` are quoted, as in `labels["0"]` or
+/// `headers["a.b"]`, so every path round-trips.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::FieldPath;
+///
+/// let path = FieldPath::new().field("items").index(0).field("price");
+/// assert_eq!(path.to_string(), "items[0].price");
+/// assert_eq!(FieldPath::from("items[0].price"), path);
+///
+/// let path = FieldPath::new().field("labels").key("0");
+/// assert_eq!(path.to_string(), r#"labels["0"]"#);
+/// assert_eq!(FieldPath::from(path.to_string()), path);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldPath {
+    segments: Vec<PathSegment>,
+}
+
+impl FieldPath {
+    /// Create an empty path referring to the value itself
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a struct field segment
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.segments.push(PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Append a sequence index segment
+    pub fn index(mut self, index: usize) -> Self {
+        self.segments.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Append a map key segment
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.segments.push(PathSegment::Key(key.into()));
+        self
+    }
+
+    /// Returns a new path with `other` appended to this one
+    pub fn join(&self, other: &FieldPath) -> FieldPath {
+        let mut segments = self.segments.clone();
+        segments.extend(other.segments.iter().cloned());
+        FieldPath { segments }
+    }
+
+    /// Returns `true` if `prefix` is a leading part of this path
+    pub fn starts_with(&self, prefix: &FieldPath) -> bool {
+        self.segments.starts_with(&prefix.segments)
+    }
+
+    /// Returns the segments of the path
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns `true` if the path has no segments
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                PathSegment::Key(key) if needs_quotes(key) => {
+                    f.write_str("[\"")?;
+                    for c in key.chars() {
+                        if matches!(c, '"' | '\\') {
+                            f.write_str("\\")?;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    f.write_str("\"]")?
+                }
+                PathSegment::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if a map key must be quoted to parse back as the same key
+fn needs_quotes(key: &str) -> bool {
+    key.parse::<usize>().is_ok() || key.contains(['.', '[', ']', '"', '\\'])
+}
+
+impl From<&str> for FieldPath {
+    fn from(s: &str) -> Self {
+        let mut path = FieldPath::new();
+        let mut name = String::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    if !name.is_empty() {
+                        path = path.field(std::mem::take(&mut name));
+                    }
+                }
+                '[' => {
+                    if !name.is_empty() {
+                        path = path.field(std::mem::take(&mut name));
+                    }
+                    if chars.next_if_eq(&'"').is_some() {
+                        let mut key = String::new();
+                        while let Some(c) = chars.next() {
+                            match c {
+                                '\\' => key.extend(chars.next()),
+                                '"' => break,
+                                c => key.push(c),
+                            }
+                        }
+                        chars.by_ref().find(|&c| c == ']');
+                        path = path.key(key);
+                    } else {
+                        let inner: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                        path = match inner.parse::<usize>() {
+                            Ok(index) => path.index(index),
+                            Err(_) => path.key(inner),
+                        };
+                    }
+                }
+                c => name.push(c),
+            }
+        }
+        if !name.is_empty() {
+            path = path.field(name);
+        }
+        path
+    }
+}
+
+impl From<String> for FieldPath {
+    fn from(s: String) -> Self {
+        FieldPath::from(s.as_str())
+    }
+}
+
+impl From<&String> for FieldPath {
+    fn from(s: &String) -> Self {
+        FieldPath::from(s.as_str())
+    }
+}
+
+impl From<usize> for FieldPath {
+    fn from(index: usize) -> Self {
+        FieldPath::new().index(index)
+    }
+}
+
+/// Collection of validation errors for a whole (possibly nested) value
+///
+/// Every error is keyed by the [`FieldPath`] of the failing value, and the
+/// `field` of each stored [`ValidationError`] is kept in sync with that path.
+/// Returned by [`Validate::validate`] so that every failing field is reported
+/// at once instead of stopping at the first failure.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::{ValidationError, ValidationErrors};
+///
+/// let mut address = ValidationErrors::new();
+/// address.add(ValidationError::new("zip", "Invalid zip code"));
+///
+/// let mut errors = ValidationErrors::new();
+/// errors.add(ValidationError::new("email", "Invalid email format"));
+/// errors.merge("address", address);
+///
+/// assert_eq!(errors.len(), 2);
+/// assert!(errors.contains("address.zip"));
+/// assert_eq!(errors.field_errors("address.zip")[0].message, "Invalid zip code");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<(FieldPath, ValidationError)>,
+}
+
+impl ValidationErrors {
+    /// Create an empty error collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error, keyed by the path in its `field`
+    pub fn add(&mut self, error: ValidationError) {
+        let path = FieldPath::from(error.field.as_str());
+        self.errors.push((path, error));
+    }
+
+    /// Add an error at an explicit path, updating its `field` to match
+    pub fn add_at(&mut self, path: impl Into<FieldPath>, mut error: ValidationError) {
+        let path = path.into();
+        error.field = path.to_string();
+        self.errors.push((path, error));
+    }
+
+    /// Add every error of `other` below `prefix`
+    pub fn merge(&mut self, prefix: impl Into<FieldPath>, other: ValidationErrors) {
+        let prefix = prefix.into();
+        for (path, error) in other.errors {
+            self.add_at(prefix.join(&path), error);
+        }
+    }
+
+    /// Add the errors of a nested validation result below `prefix`
+    pub fn merge_result(
+        &mut self,
+        prefix: impl Into<FieldPath>,
+        result: Result<(), ValidationErrors>,
+    ) {
+        if let Err(errors) = result {
+            self.merge(prefix, errors);
+        }
+    }
+
+    /// Returns `true` if no errors have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of recorded errors
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Iterates over the recorded errors in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &ValidationError> {
+        self.errors.iter().map(|(_, error)| error)
+    }
+
+    /// Iterates over the recorded errors together with their paths
+    pub fn entries(&self) -> impl Iterator<Item = (&FieldPath, &ValidationError)> {
+        self.errors.iter().map(|(path, error)| (path, error))
+    }
+
+    /// Returns the distinct paths that have errors, in insertion order
+    pub fn paths(&self) -> Vec<&FieldPath> {
+        let mut paths: Vec<&FieldPath> = Vec::new();
+        for (path, _) in &self.errors {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// Returns the errors recorded for exactly this path
+    pub fn field_errors(&self, path: impl Into<FieldPath>) -> Vec<&ValidationError> {
+        let path = path.into();
+        self.errors
+            .iter()
+            .filter(|(p, _)| *p == path)
+            .map(|(_, error)| error)
+            .collect()
+    }
+
+    /// Returns `true` if any error was recorded for this path
+    pub fn contains(&self, path: impl Into<FieldPath>) -> bool {
+        let path = path.into();
+        self.errors.iter().any(|(p, _)| *p == path)
+    }
+
+    /// Returns `Ok(())` if empty, otherwise `Err(self)`
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl From<ValidationError> for ValidationErrors {
+    fn from(error: ValidationError) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.add(error);
+        errors
+    }
+}
+
+impl Extend<ValidationError> for ValidationErrors {
+    fn extend<I: IntoIterator<Item = ValidationError>>(&mut self, iter: I) {
+        for error in iter {
+            self.add(error);
+        }
+    }
+}
+
+impl Extend<ValidationErrors> for ValidationErrors {
+    fn extend<I: IntoIterator<Item = ValidationErrors>>(&mut self, iter: I) {
+        for errors in iter {
+            self.errors.extend(errors.errors);
+        }
+    }
+}
+
+impl FromIterator<ValidationError> for ValidationErrors {
+    fn from_iter<I: IntoIterator<Item = ValidationError>>(iter: I) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.extend(iter);
+        errors
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type Item = ValidationError;
+    type IntoIter = std::iter::Map<
+        std::vec::IntoIter<(FieldPath, ValidationError)>,
+        fn((FieldPath, ValidationError)) -> ValidationError,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter().map(|(_, error)| error)
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a ValidationError;
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (FieldPath, ValidationError)>,
+        fn(&'a (FieldPath, ValidationError)) -> &'a ValidationError,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter().map(|(_, error)| error)
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+// Nested collections report errors below the index or key of each element,
+// which lets `#[validate(nested)]` work on `Vec`, `Option` and map fields.
+
+impl<T: Validate> Validate for [T] {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for (i, item) in self.iter().enumerate() {
+            errors.merge_result(i, item.validate());
+        }
+        errors.into_result()
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        (**self).validate()
+    }
+}
+
+impl<K: fmt::Display, V: Validate, S> Validate for HashMap<K, V, S> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for (key, value) in self {
+            errors.merge_result(FieldPath::new().key(key.to_string()), value.validate());
+        }
+        errors.into_result()
+    }
+}
+
+impl<K: fmt::Display, V: Validate> Validate for BTreeMap<K, V> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for (key, value) in self {
+            errors.merge_result(FieldPath::new().key(key.to_string()), value.validate());
+        }
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_error() {
+        let error = ValidationError::new("email", "Invalid email format");
+        assert_eq!(error.field, "email");
+        assert_eq!(error.message, "Invalid email format");
+    }
+
+    #[test]
+    fn test_error_code_and_params() {
+        let error = ValidationError::new("age", "Must be between 18 and 120");
+        assert_eq!(error.code, "invalid");
+        assert!(error.params.is_empty());
+
+        let error = error
+            .with_code("range.too_small")
+            .with_param("min", 18)
+            .with_param("max", 120u8)
+            .with_param("ratio", 0.5)
+            .with_param("locale", "en-US");
+        assert_eq!(error.code, "range.too_small");
+        assert_eq!(error.param("min"), Some(&ParamValue::Int(18)));
+        assert_eq!(error.param("max"), Some(&ParamValue::UInt(120)));
+        assert_eq!(error.param("ratio"), Some(&ParamValue::Float(0.5)));
+        assert_eq!(error.param("locale").unwrap().to_string(), "en-US");
+        assert_eq!(error.param("actual"), None);
+    }
+
+    #[test]
+    fn test_param_value_eq() {
+        fn assert_eq_impl<T: Eq>() {}
+        assert_eq_impl::<ValidationError>();
+        assert_eq_impl::<ValidationErrors>();
+
+        assert_eq!(ParamValue::Float(f64::NAN), ParamValue::Float(f64::NAN));
+        assert_eq!(ParamValue::Float(0.0), ParamValue::Float(-0.0));
+        assert_ne!(ParamValue::Int(1), ParamValue::UInt(1));
+    }
+
+    #[test]
+    fn test_field_path_display() {
+        let path = FieldPath::new().field("address").field("lines").index(2);
+        assert_eq!(path.to_string(), "address.lines[2]");
+
+        let path = FieldPath::new().field("headers").key("Accept").field("value");
+        assert_eq!(path.to_string(), "headers[Accept].value");
+
+        assert_eq!(FieldPath::new().index(0).field("price").to_string(), "[0].price");
+        assert_eq!(FieldPath::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_field_path_parse() {
+        assert_eq!(
+            FieldPath::from("items[0].price"),
+            FieldPath::new().field("items").index(0).field("price")
+        );
+        assert_eq!(
+            FieldPath::from("matrix[1][2]"),
+            FieldPath::new().field("matrix").index(1).index(2)
+        );
+        assert_eq!(
+            FieldPath::from("headers[Accept]"),
+            FieldPath::new().field("headers").key("Accept")
+        );
+        assert!(FieldPath::from("").is_empty());
+    }
+
+    #[test]
+    fn test_field_path_quoted_keys() {
+        for key in ["0", "42", "+1", "a.b", "x[0]", "a\"b", "a\\b", "]"] {
+            let path = FieldPath::new().field("map").key(key).field("value");
+            assert_eq!(FieldPath::from(path.to_string()), path, "{}", key);
+        }
+        assert_eq!(
+            FieldPath::new().key("a.b").index(0).to_string(),
+            r#"["a.b"][0]"#
+        );
+        assert_eq!(
+            FieldPath::new().field("labels").key("0").to_string(),
+            r#"labels["0"]"#
+        );
+        assert_ne!(
+            FieldPath::from(r#"labels["0"]"#),
+            FieldPath::from("labels[0]")
+        );
+    }
+
+    #[test]
+    fn test_field_path_join() {
+        let prefix = FieldPath::from("order.items");
+        let path = prefix.join(&FieldPath::from("[3].price"));
+        assert_eq!(path.to_string(), "order.items[3].price");
+        assert!(path.starts_with(&prefix));
+        assert!(!prefix.starts_with(&path));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let mut errors = ValidationErrors::new();
+        assert!(errors.is_empty());
+        assert!(errors.clone().into_result().is_ok());
+
+        errors.add(ValidationError::new("email", "Invalid email format"));
+        errors.add(ValidationError::new("phone", "Invalid phone number"));
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors.to_string(),
+            "Validation error for 'email': Invalid email format\n\
+             Validation error for 'phone': Invalid phone number"
+        );
+        assert!(errors.into_result().is_err());
+    }
+
+    #[test]
+    fn test_merge_nested() {
+        let mut line_errors = ValidationErrors::new();
+        line_errors.add(ValidationError::new("lines[2]", "Too long"));
+
+        let mut item_errors = ValidationErrors::new();
+        item_errors.add(ValidationError::new("price", "Invalid currency amount"));
+
+        let mut errors = ValidationErrors::new();
+        errors.merge("address", line_errors);
+        errors.merge(FieldPath::new().field("items").index(0), item_errors);
+        errors.merge_result("ignored", Ok(()));
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains("address.lines[2]"));
+        assert!(errors.contains("items[0].price"));
+        assert!(!errors.contains("items[0]"));
+
+        let fields: Vec<String> = errors.paths().iter().map(|p| p.to_string()).collect();
+        assert_eq!(fields, vec!["address.lines[2]", "items[0].price"]);
+
+        let price = errors.field_errors("items[0].price");
+        assert_eq!(price.len(), 1);
+        assert_eq!(price[0].field, "items[0].price");
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut errors: ValidationErrors = vec![
+            ValidationError::new("a", "first"),
+            ValidationError::new("a", "second"),
+        ]
+        .into_iter()
+        .collect();
+        errors.extend(vec![ValidationErrors::from(ValidationError::new("b", "third"))]);
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.field_errors("a").len(), 2);
+        assert_eq!(errors.paths().len(), 2);
+    }
+
+    struct Item {
+        price: String,
+    }
+
+    impl Validate for Item {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            if crate::currency::is_currency(&self.price, None) {
+                Ok(())
+            } else {
+                Err(ValidationError::new("price", "Invalid currency amount").into())
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_collections() {
+        let items = vec![
+            Item { price: "$1.00".to_string() },
+            Item { price: "abc".to_string() },
+        ];
+        let errors = items.validate().unwrap_err();
+        assert!(errors.contains("[1].price"));
+
+        let mut map = BTreeMap::new();
+        map.insert("gift", Item { price: "$".to_string() });
+        let errors = map.validate().unwrap_err();
+        assert!(errors.contains(FieldPath::new().key("gift").field("price")));
+
+        assert!(None::<Item>.validate().is_ok());
+    }
+}
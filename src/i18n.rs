@@ -0,0 +1,522 @@
+//! Localized validation messages
+//!
+//! This module turns the `code` and `params` of a [`ValidationError`] into
+//! localized text. Catalogs are looked up along a fallback chain such as
+//! `fr-CH` → `fr` → `en`, and fall back to the error's English `message` when
+//! no translation is found.
+//!
+//! The built-in [`Catalog`] bundles English, French, German and Spanish
+//! messages. Additional locales can be loaded from TOML-style files where
+//! keys are error codes and `{name}` placeholders are replaced with error
+//! parameters:
+//!
+//! ```toml
+//! [length]
+//! too_short = "Muss mindestens {min} Zeichen lang sein"
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use validator_rs::i18n::{Catalog, MessageCatalog};
+//! use validator_rs::string::LengthValidator;
+//! use validator_rs::Validator;
+//!
+//! let catalog = Catalog::builtin();
+//! let error = LengthValidator::new().min(8).validate("short", "password").unwrap_err();
+//!
+//! assert_eq!(catalog.localize(&error, "fr-CH"), "Doit contenir au moins 8 caractères");
+//! assert_eq!(catalog.localize(&error, "ja-JP"), "Must be at least 8 characters long");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{ValidationError, ValidationErrors};
+
+/// Locale used when no locale in the fallback chain has a message
+pub const DEFAULT_LOCALE: &str = "en";
+
+const BUILTIN_LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.toml")),
+    ("fr", include_str!("../locales/fr.toml")),
+    ("de", include_str!("../locales/de.toml")),
+    ("es", include_str!("../locales/es.toml")),
+];
+
+/// Source of message templates keyed by locale and error code
+pub trait MessageCatalog {
+    /// Returns the template for `code` in exactly `locale`, without fallback
+    fn template(&self, locale: &str, code: &str) -> Option<String>;
+
+    /// Locale tried after the locale's own fallback chain
+    fn fallback_locale(&self) -> &str {
+        DEFAULT_LOCALE
+    }
+
+    /// Returns the localized message for `error` in `locale`
+    ///
+    /// Locales are tried along the locale's [`fallback_chain`], ending with
+    /// [`fallback_locale`](MessageCatalog::fallback_locale), and the error's own
+    /// `message` is returned if none of them has a template for its code.
+    fn localize(&self, error: &ValidationError, locale: &str) -> String {
+        let mut chain = subtag_chain(locale);
+        chain.push(self.fallback_locale().to_string());
+
+        chain
+            .iter()
+            .find_map(|candidate| self.template(candidate, &error.code))
+            .map(|template| render(&template, error))
+            .unwrap_or_else(|| error.message.clone())
+    }
+
+    /// Returns the localized messages of every error, in order
+    fn localize_all(&self, errors: &ValidationErrors, locale: &str) -> Vec<(String, String)> {
+        errors
+            .iter()
+            .map(|error| (error.field.clone(), self.localize(error, locale)))
+            .collect()
+    }
+}
+
+/// Returns the locales to try for `locale`, most specific first
+///
+/// Subtags are removed one at a time and the [`DEFAULT_LOCALE`] is appended,
+/// so `fr-CH` yields `["fr-CH", "fr", "en"]`. Underscores are accepted as
+/// separators (`fr_CH`).
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::i18n::fallback_chain;
+///
+/// assert_eq!(fallback_chain("fr-CH"), vec!["fr-CH", "fr", "en"]);
+/// assert_eq!(fallback_chain("zh_Hant_TW"), vec!["zh-Hant-TW", "zh-Hant", "zh", "en"]);
+/// assert_eq!(fallback_chain("en-GB"), vec!["en-GB", "en"]);
+/// ```
+pub fn fallback_chain(locale: &str) -> Vec<String> {
+    let mut chain = subtag_chain(locale);
+    if !chain.iter().any(|l| l.eq_ignore_ascii_case(DEFAULT_LOCALE)) {
+        chain.push(DEFAULT_LOCALE.to_string());
+    }
+    chain
+}
+
+/// Returns `locale` followed by its parents, without any default locale
+fn subtag_chain(locale: &str) -> Vec<String> {
+    let normalized = locale.trim().replace('_', "-");
+    let mut chain = Vec::new();
+
+    let mut current = normalized.as_str();
+    while !current.is_empty() {
+        chain.push(current.to_string());
+        current = match current.rfind('-') {
+            Some(pos) => &current[..pos],
+            None => "",
+        };
+    }
+    chain
+}
+
+/// Replaces `{name}` placeholders in `template` with the error's parameters
+///
+/// `{field}` is replaced with the error's field. Unknown placeholders are left
+/// untouched.
+pub fn render(template: &str, error: &ValidationError) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match (name, error.params.get(name)) {
+                    (_, Some(value)) => result.push_str(&value.to_string()),
+                    ("field", None) => result.push_str(&error.field),
+                    _ => result.push_str(&rest[start..start + end + 2]),
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Error returned when a catalog file cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogError {
+    /// 1-based line number of the error
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Catalog error on line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// In-memory message catalog
+///
+/// Locales are matched case-insensitively.
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    messages: HashMap<String, HashMap<String, String>>,
+    fallback_locale: String,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self {
+            messages: HashMap::new(),
+            fallback_locale: DEFAULT_LOCALE.to_string(),
+        }
+    }
+}
+
+impl Catalog {
+    /// Create an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a catalog with the bundled English, French, German and Spanish messages
+    pub fn builtin() -> Self {
+        let mut catalog = Self::new();
+        for (locale, source) in BUILTIN_LOCALES {
+            catalog
+                .load_str(locale, source)
+                .expect("Bundled message catalog must be valid");
+        }
+        catalog
+    }
+
+    /// Set the locale tried last (defaults to `en`)
+    pub fn with_fallback_locale(mut self, locale: impl Into<String>) -> Self {
+        self.fallback_locale = locale.into();
+        self
+    }
+
+    /// Add or replace a single message template
+    pub fn insert(
+        &mut self,
+        locale: &str,
+        code: impl Into<String>,
+        template: impl Into<String>,
+    ) {
+        self.messages
+            .entry(locale.to_ascii_lowercase())
+            .or_default()
+            .insert(code.into(), template.into());
+    }
+
+    /// Load message templates for `locale` from a TOML-style source
+    ///
+    /// Supports `key = "value"` pairs, `[section]` headers (prefixing keys with
+    /// `section.`), dotted or quoted keys, `#` comments and the escapes `\"`,
+    /// `\\`, `\n`, `\t` and `\uXXXX`. Existing templates are overwritten.
+    pub fn load_str(&mut self, locale: &str, source: &str) -> Result<(), CatalogError> {
+        for (code, template) in parse_catalog(source)? {
+            self.insert(locale, code, template);
+        }
+        Ok(())
+    }
+
+    /// Load message templates for `locale` from a file
+    pub fn load_file(
+        &mut self,
+        locale: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), CatalogError> {
+        let source = std::fs::read_to_string(path).map_err(|e| CatalogError {
+            line: 0,
+            message: e.to_string(),
+        })?;
+        self.load_str(locale, &source)
+    }
+
+    /// Returns the locales that have at least one message
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.messages.keys().map(String::as_str).collect();
+        locales.sort_unstable();
+        locales
+    }
+}
+
+impl MessageCatalog for Catalog {
+    fn template(&self, locale: &str, code: &str) -> Option<String> {
+        self.messages
+            .get(&locale.to_ascii_lowercase())
+            .and_then(|messages| messages.get(code))
+            .cloned()
+    }
+
+    fn fallback_locale(&self) -> &str {
+        &self.fallback_locale
+    }
+}
+
+/// Parses the supported TOML subset into `(key, value)` pairs
+fn parse_catalog(source: &str) -> Result<Vec<(String, String)>, CatalogError> {
+    let mut entries = Vec::new();
+    let mut section = String::new();
+
+    for (index, raw_line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let error = |message: &str| CatalogError {
+            line: line_number,
+            message: message.to_string(),
+        };
+        let line = raw_line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = strip_comment(header);
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| error("Unterminated section header"))?;
+            section = parse_key(name).ok_or_else(|| error("Invalid section name"))?;
+            continue;
+        }
+
+        let eq = line
+            .find('=')
+            .ok_or_else(|| error("Expected `key = \"value\"`"))?;
+        let key = parse_key(&line[..eq]).ok_or_else(|| error("Invalid key"))?;
+        let value = parse_string(line[eq + 1..].trim()).map_err(|m| error(&m))?;
+
+        let key = if section.is_empty() {
+            key
+        } else {
+            format!("{}.{}", section, key)
+        };
+        entries.push((key, value));
+    }
+
+    Ok(entries)
+}
+
+/// Removes a trailing `# comment` from a section header
+fn strip_comment(s: &str) -> &str {
+    match s.find('#') {
+        Some(pos) => s[..pos].trim_end(),
+        None => s.trim_end(),
+    }
+}
+
+/// Parses a bare, quoted or dotted key into its dotted form
+fn parse_key(raw: &str) -> Option<String> {
+    let parts: Option<Vec<String>> = raw
+        .split('.')
+        .map(|part| {
+            let part = part.trim();
+            if let Some(quoted) = part.strip_prefix('"').and_then(|p| p.strip_suffix('"')) {
+                Some(quoted.to_string())
+            } else if !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                Some(part.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    parts.map(|p| p.join("."))
+}
+
+/// Parses a double-quoted string value followed by an optional comment
+fn parse_string(raw: &str) -> Result<String, String> {
+    let mut chars = raw.chars();
+    if chars.next() != Some('"') {
+        return Err("Expected a double-quoted string".to_string());
+    }
+
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            None => return Err("Unterminated string".to_string()),
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("Invalid unicode escape '\\u{}'", hex))?;
+                    value.push(c);
+                }
+                Some(other) => return Err(format!("Invalid escape '\\{}'", other)),
+                None => return Err("Unterminated string".to_string()),
+            },
+            Some(c) => value.push(c),
+        }
+    }
+
+    let rest = chars.as_str().trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err("Unexpected characters after string".to_string());
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::RangeValidator;
+    use crate::string::LengthValidator;
+    use crate::Validator;
+
+    #[test]
+    fn test_fallback_chain() {
+        assert_eq!(fallback_chain("fr-CH"), vec!["fr-CH", "fr", "en"]);
+        assert_eq!(fallback_chain("fr"), vec!["fr", "en"]);
+        assert_eq!(fallback_chain("en-US"), vec!["en-US", "en"]);
+        assert_eq!(fallback_chain("pt_BR"), vec!["pt-BR", "pt", "en"]);
+        assert_eq!(fallback_chain(""), vec!["en"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let error = ValidationError::new("age", "Must be at least 18")
+            .with_code("range.too_small")
+            .with_param("min", 18);
+        assert_eq!(render("{field} must be >= {min}", &error), "age must be >= 18");
+        assert_eq!(render("{unknown} {min", &error), "{unknown} {min");
+    }
+
+    #[test]
+    fn test_builtin_catalog() {
+        let catalog = Catalog::builtin();
+        assert_eq!(catalog.locales(), vec!["de", "en", "es", "fr"]);
+
+        let error = LengthValidator::new()
+            .max(5)
+            .validate("too long", "name")
+            .unwrap_err();
+        assert_eq!(catalog.localize(&error, "en"), "Must not exceed 5 characters");
+        assert_eq!(catalog.localize(&error, "de-AT"), "Darf höchstens 5 Zeichen lang sein");
+        assert_eq!(catalog.localize(&error, "es"), "No debe superar los 5 caracteres");
+        assert_eq!(catalog.localize(&error, "fr_CH"), "Ne doit pas dépasser 5 caractères");
+        assert_eq!(catalog.localize(&error, "FR"), "Ne doit pas dépasser 5 caractères");
+    }
+
+    #[test]
+    fn test_builtin_catalogs_cover_english_codes() {
+        let catalog = Catalog::builtin();
+        let english = parse_catalog(BUILTIN_LOCALES[0].1).unwrap();
+        for (locale, _) in BUILTIN_LOCALES {
+            for (code, _) in &english {
+                assert!(
+                    catalog.template(locale, code).is_some(),
+                    "Missing '{}' in '{}' catalog",
+                    code,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_locale_and_fallback() {
+        let mut catalog = Catalog::builtin();
+        catalog
+            .load_str(
+                "fr-CA",
+                "[range]\ntoo_large = \"Doit être au plus {max} (Canada)\" # override\n",
+            )
+            .unwrap();
+
+        let error = RangeValidator::new()
+            .max(10)
+            .validate(&11, "quantity")
+            .unwrap_err();
+        assert_eq!(catalog.localize(&error, "fr-CA"), "Doit être au plus 10 (Canada)");
+        assert_eq!(catalog.localize(&error, "fr-FR"), "Doit être inférieur ou égal à 10");
+
+        let catalog = Catalog::builtin().with_fallback_locale("de");
+        assert_eq!(catalog.localize(&error, "it"), "Darf höchstens 10 sein");
+        assert_eq!(Catalog::new().localize(&error, "it"), "Must be at most 10");
+    }
+
+    #[test]
+    fn test_localize_two_sided_range() {
+        let error = RangeValidator::new()
+            .min(1)
+            .max(10)
+            .validate(&11, "quantity")
+            .unwrap_err();
+        let catalog = Catalog::builtin();
+        assert_eq!(catalog.localize(&error, "en"), "Must be between 1 and 10");
+        assert_eq!(catalog.localize(&error, "de"), "Muss zwischen 1 und 10 liegen");
+    }
+
+    #[test]
+    fn test_unknown_code_uses_message() {
+        let error = ValidationError::new("field", "Custom message").with_code("custom.code");
+        assert_eq!(Catalog::builtin().localize(&error, "fr"), "Custom message");
+    }
+
+    #[test]
+    fn test_localize_all() {
+        let mut errors = ValidationErrors::new();
+        errors.add(ValidationError::new("email", "Invalid email format").with_code("email.invalid"));
+        let messages = Catalog::builtin().localize_all(&errors, "es");
+        assert_eq!(
+            messages,
+            vec![(
+                "email".to_string(),
+                "Formato de correo electrónico no válido".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_catalog() {
+        let entries = parse_catalog(
+            "# comment\n\
+             top = \"Top\"\n\
+             email.invalid = \"Quote \\\" and \\u00e9\"\n\
+             [\"length\"]\n\
+             too_short = \"Short\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("top".to_string(), "Top".to_string()),
+                ("email.invalid".to_string(), "Quote \" and é".to_string()),
+                ("length.too_short".to_string(), "Short".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_catalog("valid = \"ok\"\nmissing value\n").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        assert!(parse_catalog("key = unquoted").is_err());
+        assert!(parse_catalog("key = \"unterminated").is_err());
+        assert!(parse_catalog("[section").is_err());
+        assert!(parse_catalog("bad key = \"x\"").is_err());
+    }
+}
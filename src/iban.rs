@@ -0,0 +1,565 @@
+//! IBAN (International Bank Account Number) validation
+//!
+//! Validates IBANs against the ISO 13616 structure, the length and BBAN
+//! (Basic Bank Account Number) format of each country in the IBAN registry,
+//! and the ISO 7064 MOD 97-10 checksum.
+
+use crate::checksum::is_valid_iso7064_mod97_10;
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
+use std::fmt;
+
+/// IBAN structure of a registry country
+struct CountryFormat {
+    /// ISO 3166-1 alpha-2 country code
+    country: &'static str,
+    /// Total IBAN length
+    length: usize,
+    /// BBAN format in registry notation: `n` digits, `a` uppercase letters,
+    /// `c` uppercase letters and digits, each preceded by a fixed length
+    bban: &'static str,
+    /// Position of the bank code within the BBAN
+    bank_code: (usize, usize),
+    /// Position of the account number within the BBAN
+    account_number: (usize, usize),
+}
+
+const fn country(
+    country: &'static str,
+    length: usize,
+    bban: &'static str,
+    bank_code: (usize, usize),
+    account_number: (usize, usize),
+) -> CountryFormat {
+    CountryFormat {
+        country,
+        length,
+        bban,
+        bank_code,
+        account_number,
+    }
+}
+
+/// Countries of the SWIFT IBAN registry
+const COUNTRIES: &[CountryFormat] = &[
+    country("AD", 24, "4!n4!n12!c", (0, 4), (8, 20)),
+    country("AE", 23, "3!n16!n", (0, 3), (3, 19)),
+    country("AL", 28, "8!n16!c", (0, 3), (8, 24)),
+    country("AT", 20, "5!n11!n", (0, 5), (5, 16)),
+    country("AZ", 28, "4!a20!c", (0, 4), (4, 24)),
+    country("BA", 20, "3!n3!n8!n2!n", (0, 3), (6, 14)),
+    country("BE", 16, "3!n7!n2!n", (0, 3), (3, 10)),
+    country("BG", 22, "4!a4!n2!n8!c", (0, 4), (10, 18)),
+    country("BH", 22, "4!a14!c", (0, 4), (4, 18)),
+    country("BI", 27, "5!n5!n11!n2!n", (0, 5), (10, 21)),
+    country("BR", 29, "8!n5!n10!n1!a1!c", (0, 8), (13, 23)),
+    country("BY", 28, "4!c4!n16!c", (0, 4), (8, 24)),
+    country("CH", 21, "5!n12!c", (0, 5), (5, 17)),
+    country("CR", 22, "4!n14!n", (0, 4), (4, 18)),
+    country("CY", 28, "3!n5!n16!c", (0, 3), (8, 24)),
+    country("CZ", 24, "4!n6!n10!n", (0, 4), (4, 20)),
+    country("DE", 22, "8!n10!n", (0, 8), (8, 18)),
+    country("DJ", 27, "5!n5!n11!n2!n", (0, 5), (10, 21)),
+    country("DK", 18, "4!n9!n1!n", (0, 4), (4, 14)),
+    country("DO", 28, "4!c20!n", (0, 4), (4, 24)),
+    country("EE", 20, "2!n14!n", (0, 2), (2, 16)),
+    country("EG", 29, "4!n4!n17!n", (0, 4), (8, 25)),
+    country("ES", 24, "4!n4!n1!n1!n10!n", (0, 4), (10, 20)),
+    country("FI", 18, "3!n11!n", (0, 3), (3, 14)),
+    country("FK", 18, "2!a12!n", (0, 2), (2, 14)),
+    country("FO", 18, "4!n9!n1!n", (0, 4), (4, 14)),
+    country("FR", 27, "5!n5!n11!c2!n", (0, 5), (10, 21)),
+    country("GB", 22, "4!a6!n8!n", (0, 4), (10, 18)),
+    country("GE", 22, "2!a16!n", (0, 2), (2, 18)),
+    country("GI", 23, "4!a15!c", (0, 4), (4, 19)),
+    country("GL", 18, "4!n9!n1!n", (0, 4), (4, 14)),
+    country("GR", 27, "3!n4!n16!c", (0, 3), (7, 23)),
+    country("GT", 28, "4!c20!c", (0, 4), (4, 24)),
+    country("HN", 28, "4!a20!n", (0, 4), (4, 24)),
+    country("HR", 21, "7!n10!n", (0, 7), (7, 17)),
+    country("HU", 28, "3!n4!n1!n15!n1!n", (0, 3), (8, 23)),
+    country("IE", 22, "4!a6!n8!n", (0, 4), (10, 18)),
+    country("IL", 23, "3!n3!n13!n", (0, 3), (6, 19)),
+    country("IQ", 23, "4!a3!n12!n", (0, 4), (7, 19)),
+    country("IS", 26, "4!n2!n6!n10!n", (0, 4), (4, 12)),
+    country("IT", 27, "1!a5!n5!n12!c", (1, 6), (11, 23)),
+    country("JO", 30, "4!a4!n18!c", (0, 4), (8, 26)),
+    country("KW", 30, "4!a22!c", (0, 4), (4, 26)),
+    country("KZ", 20, "3!n13!c", (0, 3), (3, 16)),
+    country("LB", 28, "4!n20!c", (0, 4), (4, 24)),
+    country("LC", 32, "4!a24!c", (0, 4), (4, 28)),
+    country("LI", 21, "5!n12!c", (0, 5), (5, 17)),
+    country("LT", 20, "5!n11!n", (0, 5), (5, 16)),
+    country("LU", 20, "3!n13!c", (0, 3), (3, 16)),
+    country("LV", 21, "4!a13!c", (0, 4), (4, 17)),
+    country("LY", 25, "3!n3!n15!n", (0, 3), (6, 21)),
+    country("MC", 27, "5!n5!n11!c2!n", (0, 5), (10, 21)),
+    country("MD", 24, "2!c18!c", (0, 2), (2, 20)),
+    country("ME", 22, "3!n13!n2!n", (0, 3), (3, 16)),
+    country("MK", 19, "3!n10!c2!n", (0, 3), (3, 13)),
+    country("MN", 20, "4!n12!n", (0, 4), (4, 16)),
+    country("MR", 27, "5!n5!n11!n2!n", (0, 5), (10, 21)),
+    country("MT", 31, "4!a5!n18!c", (0, 4), (9, 27)),
+    country("MU", 30, "4!a2!n2!n12!n3!n3!a", (0, 6), (8, 20)),
+    country("NI", 28, "4!a20!n", (0, 4), (4, 24)),
+    country("NL", 18, "4!a10!n", (0, 4), (4, 14)),
+    country("NO", 15, "4!n6!n1!n", (0, 4), (4, 10)),
+    country("OM", 23, "3!n16!c", (0, 3), (3, 19)),
+    country("PK", 24, "4!a16!c", (0, 4), (4, 20)),
+    country("PL", 28, "8!n16!n", (0, 8), (8, 24)),
+    country("PS", 29, "4!a21!c", (0, 4), (4, 25)),
+    country("PT", 25, "4!n4!n11!n2!n", (0, 4), (8, 19)),
+    country("QA", 29, "4!a21!c", (0, 4), (4, 25)),
+    country("RO", 24, "4!a16!c", (0, 4), (4, 20)),
+    country("RS", 22, "3!n13!n2!n", (0, 3), (3, 16)),
+    country("RU", 33, "9!n5!n15!c", (0, 9), (14, 29)),
+    country("SA", 24, "2!n18!c", (0, 2), (2, 20)),
+    country("SC", 31, "4!a2!n2!n16!n3!a", (0, 6), (8, 24)),
+    country("SD", 18, "2!n12!n", (0, 2), (2, 14)),
+    country("SE", 24, "3!n17!n", (0, 3), (3, 20)),
+    country("SI", 19, "5!n8!n2!n", (0, 5), (5, 13)),
+    country("SK", 24, "4!n6!n10!n", (0, 4), (4, 20)),
+    country("SM", 27, "1!a5!n5!n12!c", (1, 6), (11, 23)),
+    country("SO", 23, "4!n3!n12!n", (0, 4), (7, 19)),
+    country("ST", 25, "8!n11!n2!n", (0, 4), (8, 19)),
+    country("SV", 28, "4!a20!n", (0, 4), (4, 24)),
+    country("TL", 23, "3!n14!n2!n", (0, 3), (3, 17)),
+    country("TN", 24, "2!n3!n13!n2!n", (0, 2), (5, 18)),
+    country("TR", 26, "5!n1!n16!c", (0, 5), (6, 22)),
+    country("UA", 29, "6!n19!c", (0, 6), (6, 25)),
+    country("VA", 22, "3!n15!n", (0, 3), (3, 18)),
+    country("VG", 24, "4!a16!n", (0, 4), (4, 20)),
+    country("XK", 20, "4!n10!n2!n", (0, 4), (4, 14)),
+    country("YE", 30, "4!a4!n18!c", (0, 4), (8, 26)),
+];
+
+fn country_format(country: &str) -> Option<&'static CountryFormat> {
+    COUNTRIES.iter().find(|format| format.country == country)
+}
+
+/// Returns `true` if a BBAN matches a registry format such as `4!a6!n8!n`
+fn matches_bban_format(bban: &str, format: &str) -> bool {
+    let mut rest = bban;
+    let mut chars = format.chars().peekable();
+    while chars.peek().is_some() {
+        let mut length = 0;
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            length = length * 10 + digit as usize;
+            chars.next();
+        }
+        chars.next_if_eq(&'!');
+        let allowed: fn(&char) -> bool = match chars.next() {
+            Some('n') => |c| c.is_ascii_digit(),
+            Some('a') => |c| c.is_ascii_uppercase(),
+            Some('c') => |c| c.is_ascii_uppercase() || c.is_ascii_digit(),
+            _ => return false,
+        };
+        match rest.get(..length) {
+            Some(segment) if segment.chars().all(|c| allowed(&c)) => rest = &rest[length..],
+            _ => return false,
+        }
+    }
+    rest.is_empty()
+}
+
+/// Reason an IBAN failed to parse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IbanError {
+    /// The input is empty
+    Empty,
+    /// The IBAN contains a character other than letters, digits and spaces
+    InvalidCharacter(char),
+    /// The country code is not in the IBAN registry
+    UnknownCountry(String),
+    /// The length does not match the country's IBAN length
+    InvalidLength {
+        /// The IBAN length of the country
+        expected: usize,
+        /// The length of the input without spaces
+        actual: usize,
+    },
+    /// The BBAN does not match the country's format
+    InvalidFormat,
+    /// The check digits do not match (ISO 7064 MOD 97-10)
+    InvalidChecksum,
+}
+
+impl fmt::Display for IbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IbanError::Empty => f.write_str("IBAN is empty"),
+            IbanError::InvalidCharacter(c) => write!(f, "IBAN contains invalid character '{}'", c),
+            IbanError::UnknownCountry(country) => {
+                write!(f, "IBAN country '{}' is not supported", country)
+            }
+            IbanError::InvalidLength { expected, actual } => {
+                write!(f, "IBAN has {} characters, expected {}", actual, expected)
+            }
+            IbanError::InvalidFormat => f.write_str("IBAN has an invalid account format"),
+            IbanError::InvalidChecksum => f.write_str("IBAN has invalid check digits"),
+        }
+    }
+}
+
+impl std::error::Error for IbanError {}
+
+/// A validated IBAN, stored in electronic format (uppercase, no spaces)
+///
+/// Displays in print format, in groups of four characters.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::iban::Iban;
+///
+/// let iban = Iban::parse("GB82 WEST 1234 5698 7654 32").unwrap();
+/// assert_eq!(iban.country_code(), "GB");
+/// assert_eq!(iban.check_digits(), "82");
+/// assert_eq!(iban.bank_code(), "WEST");
+/// assert_eq!(iban.account_number(), "98765432");
+/// assert_eq!(iban.as_str(), "GB82WEST12345698765432");
+/// assert_eq!(iban.to_string(), "GB82 WEST 1234 5698 7654 32");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iban {
+    value: String,
+    bank_code: (usize, usize),
+    account_number: (usize, usize),
+}
+
+impl Iban {
+    /// Parses and validates an IBAN in electronic or print format
+    ///
+    /// Spaces are ignored and lowercase letters are accepted.
+    pub fn parse(value: &str) -> Result<Self, IbanError> {
+        let mut iban = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                ' ' => {}
+                'A'..='Z' | '0'..='9' => iban.push(c),
+                'a'..='z' => iban.push(c.to_ascii_uppercase()),
+                _ => return Err(IbanError::InvalidCharacter(c)),
+            }
+        }
+        if iban.is_empty() {
+            return Err(IbanError::Empty);
+        }
+
+        let country = iban.get(..2).unwrap_or(&iban);
+        let format = country_format(country)
+            .ok_or_else(|| IbanError::UnknownCountry(country.to_string()))?;
+        if iban.len() != format.length {
+            return Err(IbanError::InvalidLength {
+                expected: format.length,
+                actual: iban.len(),
+            });
+        }
+
+        let (prefix, bban) = iban.split_at(4);
+        if !prefix[2..].chars().all(|c| c.is_ascii_digit())
+            || !matches_bban_format(bban, format.bban)
+        {
+            return Err(IbanError::InvalidFormat);
+        }
+        // Check digits are 02-98; 00, 01 and 99 are congruent to 97, 98 and 02
+        if matches!(&prefix[2..], "00" | "01" | "99")
+            || !is_valid_iso7064_mod97_10(&format!("{}{}", bban, prefix))
+        {
+            return Err(IbanError::InvalidChecksum);
+        }
+
+        Ok(Self {
+            value: iban,
+            bank_code: format.bank_code,
+            account_number: format.account_number,
+        })
+    }
+
+    /// Returns the IBAN in electronic format
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 country code
+    pub fn country_code(&self) -> &str {
+        &self.value[..2]
+    }
+
+    /// Returns the two check digits
+    pub fn check_digits(&self) -> &str {
+        &self.value[2..4]
+    }
+
+    /// Returns the country-specific BBAN
+    pub fn bban(&self) -> &str {
+        &self.value[4..]
+    }
+
+    /// Returns the bank identifier within the BBAN
+    pub fn bank_code(&self) -> &str {
+        &self.bban()[self.bank_code.0..self.bank_code.1]
+    }
+
+    /// Returns the account number within the BBAN
+    pub fn account_number(&self) -> &str {
+        &self.bban()[self.account_number.0..self.account_number.1]
+    }
+}
+
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, group) in self.value.as_bytes().chunks(4).enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(std::str::from_utf8(group).map_err(|_| fmt::Error)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Validates an IBAN in electronic or print format
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::iban::is_valid_iban;
+///
+/// assert!(is_valid_iban("DE89 3704 0044 0532 0130 00"));
+/// assert!(is_valid_iban("NL91ABNA0417164300"));
+/// assert!(!is_valid_iban("DE89 3704 0044 0532 0130 01")); // wrong check digits
+/// assert!(!is_valid_iban("US12 3456 7890"));
+/// ```
+pub fn is_valid_iban(value: &str) -> bool {
+    Iban::parse(value).is_ok()
+}
+
+/// Returns `true` if the country code is in the IBAN registry
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::iban::is_iban_country;
+///
+/// assert!(is_iban_country("FR"));
+/// assert!(!is_iban_country("US"));
+/// ```
+pub fn is_iban_country(country: &str) -> bool {
+    country_format(country).is_some()
+}
+
+/// IBAN validator implementing [`Validator`]
+///
+/// Fails with code `iban.invalid`, or `iban.country_not_allowed` (with a
+/// `country` parameter) when the country is not accepted.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::iban::IbanValidator;
+/// use validator_rs::validator::Validator;
+///
+/// let validator = IbanValidator::new().allowed_countries(["DE", "FR"]);
+/// assert!(validator.validate("DE89370400440532013000", "iban").is_ok());
+/// assert!(validator.validate("GB82WEST12345698765432", "iban").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IbanValidator {
+    /// If non-empty, the IBAN country must be one of these
+    pub allowed_countries: Vec<String>,
+}
+
+impl IbanValidator {
+    /// Create a new IbanValidator accepting any registry country
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the accepted countries
+    pub fn allowed_countries<I, S>(mut self, countries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_countries = countries.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for IbanValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let iban = Iban::parse(value.as_ref())
+            .map_err(|_| ValidationError::new(field, "Invalid IBAN").with_code("iban.invalid"))?;
+
+        let country = iban.country_code();
+        if !self.allowed_countries.is_empty()
+            && !self
+                .allowed_countries
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(country))
+        {
+            return Err(ValidationError::new(field, "IBAN country is not accepted")
+                .with_code("iban.country_not_allowed")
+                .with_param("country", country));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[&str] = &[
+        "AT611904300234573201",
+        "BE68539007547034",
+        "BR1800360305000010009795493C1",
+        "CH9300762011623852957",
+        "CR05015202001026284066",
+        "DE89370400440532013000",
+        "DO28BAGR00000001212453611324",
+        "EE382200221020145685",
+        "ES9121000418450200051332",
+        "FI2112345600000785",
+        "FR1420041010050500013M02606",
+        "GB82WEST12345698765432",
+        "GT82TRAJ01020000001210029690",
+        "HN54PISA00000000000000123124",
+        "HU42117730161111101800000000",
+        "IQ98NBIQ850123456789012",
+        "IS140159260076545510730339",
+        "IT60X0542811101000000123456",
+        "KZ86125KZT5004100100",
+        "LC55HEMM000100010012001200023015",
+        "MT84MALT011000012345MTLCAST001S",
+        "MU17BOMM0101101030300200000MUR",
+        "NL91ABNA0417164300",
+        "NO9386011117947",
+        "PL61109010140000071219812874",
+        "RU0204452560040702810412345678901",
+        "SC18SSCB11010000000000001497USD",
+        "SE4550000000058398257466",
+        "SV62CENR00000000000000700025",
+        "XK051212012345678906",
+        "YE15CBYE0001018861234567891234",
+    ];
+
+    #[test]
+    fn test_registry_examples() {
+        for example in EXAMPLES {
+            assert!(is_valid_iban(example), "{}", example);
+        }
+    }
+
+    #[test]
+    fn test_country_formats() {
+        for format in COUNTRIES {
+            let bban_length: usize = format
+                .bban
+                .split(|c: char| c.is_ascii_alphabetic() || c == '!')
+                .filter_map(|length| length.parse::<usize>().ok())
+                .sum();
+            assert_eq!(bban_length + 4, format.length, "{}", format.country);
+            assert!(format.bank_code.1 <= bban_length, "{}", format.country);
+            assert!(format.account_number.1 <= bban_length, "{}", format.country);
+        }
+    }
+
+    #[test]
+    fn test_iban_parts() {
+        let iban: Iban = "de89 3704 0044 0532 0130 00".parse().unwrap();
+        assert_eq!(iban.as_str(), "DE89370400440532013000");
+        assert_eq!(iban.bank_code(), "37040044");
+        assert_eq!(iban.account_number(), "0532013000");
+        assert_eq!(iban.bban(), "370400440532013000");
+
+        let iban = Iban::parse("IT60X0542811101000000123456").unwrap();
+        assert_eq!(iban.bank_code(), "05428");
+        assert_eq!(iban.account_number(), "000000123456");
+        assert_eq!(iban.to_string(), "IT60 X054 2811 1010 0000 0123 456");
+    }
+
+    #[test]
+    fn test_iban_errors() {
+        assert_eq!(Iban::parse(""), Err(IbanError::Empty));
+        assert_eq!(Iban::parse("   "), Err(IbanError::Empty));
+        assert_eq!(
+            Iban::parse("DE89-3704"),
+            Err(IbanError::InvalidCharacter('-'))
+        );
+        assert_eq!(
+            Iban::parse("US64SVBKUS6S3300958879"),
+            Err(IbanError::UnknownCountry("US".to_string()))
+        );
+        assert_eq!(
+            Iban::parse("D"),
+            Err(IbanError::UnknownCountry("D".to_string()))
+        );
+        assert_eq!(
+            Iban::parse("DE8937040044053201300"),
+            Err(IbanError::InvalidLength {
+                expected: 22,
+                actual: 21
+            })
+        );
+        // Letters where the German BBAN requires digits
+        assert_eq!(
+            Iban::parse("DE89370400440532013ABC"),
+            Err(IbanError::InvalidFormat)
+        );
+        assert_eq!(
+            Iban::parse("GBAAWEST12345698765432"),
+            Err(IbanError::InvalidFormat)
+        );
+        assert_eq!(
+            Iban::parse("GB83WEST12345698765432"),
+            Err(IbanError::InvalidChecksum)
+        );
+
+        // Swapping valid check digits for their mod-97 congruent forms
+        for (valid, swapped) in [
+            ("GB98WEST12345698765435", "GB01WEST12345698765435"),
+            ("GB97WEST12345698765453", "GB00WEST12345698765453"),
+            ("GB02WEST12345698765514", "GB99WEST12345698765514"),
+        ] {
+            assert!(is_valid_iban(valid), "{}", valid);
+            assert_eq!(Iban::parse(swapped), Err(IbanError::InvalidChecksum));
+        }
+    }
+
+    #[test]
+    fn test_bban_format() {
+        assert!(matches_bban_format("WEST12345698765432", "4!a6!n8!n"));
+        assert!(!matches_bban_format("WEST1234569876543", "4!a6!n8!n"));
+        assert!(!matches_bban_format("WEST123456987654321", "4!a6!n8!n"));
+        assert!(!matches_bban_format("west12345698765432", "4!a6!n8!n"));
+        assert!(matches_bban_format("0001A2", "4!n2!c"));
+    }
+
+    #[test]
+    fn test_iban_validator() {
+        let validator = IbanValidator::new();
+        assert!(validator
+            .validate("FR1420041010050500013M02606", "iban")
+            .is_ok());
+        let error = validator.validate("FR14", "iban").unwrap_err();
+        assert_eq!(error.code, "iban.invalid");
+
+        let validator = IbanValidator::new().allowed_countries(["de"]);
+        assert!(validator.validate("DE89370400440532013000", "iban").is_ok());
+        let error = validator
+            .validate("NL91ABNA0417164300", "iban")
+            .unwrap_err();
+        assert_eq!(error.code, "iban.country_not_allowed");
+        assert_eq!(error.param("country").unwrap().to_string(), "NL");
+    }
+}
@@ -0,0 +1,376 @@
+//! Internationalized domain names
+//!
+//! Converts domain names between their Unicode form and the ASCII-compatible
+//! `xn--` form using Punycode (RFC 3492). Labels are mapped by lowercasing and
+//! treating ideographic full stops as label separators, which covers the
+//! common cases of IDNA (RFC 5891) without Unicode normalization tables.
+
+use std::fmt;
+
+/// Maximum length of a domain label in octets
+const MAX_LABEL_LEN: usize = 63;
+/// Maximum length of a domain name in octets
+const MAX_DOMAIN_LEN: usize = 253;
+/// Prefix of ASCII-compatible encoded labels
+const ACE_PREFIX: &str = "xn--";
+
+/// Reason a domain could not be converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdnaError {
+    /// A label is empty, e.g. `example..com`
+    EmptyLabel,
+    /// A label exceeds 63 octets once encoded
+    LabelTooLong,
+    /// The domain exceeds 253 octets once encoded
+    DomainTooLong,
+    /// A label contains a character that is not allowed in host names
+    InvalidCharacter(char),
+    /// An `xn--` label is not valid Punycode
+    InvalidPunycode,
+}
+
+impl fmt::Display for IdnaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdnaError::EmptyLabel => f.write_str("domain contains an empty label"),
+            IdnaError::LabelTooLong => f.write_str("domain label exceeds 63 characters"),
+            IdnaError::DomainTooLong => f.write_str("domain exceeds 253 characters"),
+            IdnaError::InvalidCharacter(c) => {
+                write!(f, "domain contains invalid character '{}'", c)
+            }
+            IdnaError::InvalidPunycode => f.write_str("domain contains invalid punycode"),
+        }
+    }
+}
+
+impl std::error::Error for IdnaError {}
+
+/// Converts a domain to its ASCII form, encoding Unicode labels as `xn--` Punycode
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::idna::to_ascii;
+///
+/// assert_eq!(to_ascii("exämple.de").unwrap(), "xn--exmple-cua.de");
+/// assert_eq!(to_ascii("例子.广告").unwrap(), "xn--fsqu00a.xn--4rr70v");
+/// assert_eq!(to_ascii("Example.COM").unwrap(), "example.com");
+/// assert!(to_ascii("exa mple.com").is_err());
+/// ```
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    let mut labels = Vec::new();
+    for label in split_labels(domain) {
+        let label = label.to_lowercase();
+        if label.is_empty() {
+            return Err(IdnaError::EmptyLabel);
+        }
+        if let Some(c) = label
+            .chars()
+            .find(|&c| !(c == '-' || c.is_ascii_alphanumeric() || is_unicode_label_char(c)))
+        {
+            return Err(IdnaError::InvalidCharacter(c));
+        }
+        // Every character takes at least one octet once encoded
+        if label.chars().count() > MAX_LABEL_LEN {
+            return Err(IdnaError::LabelTooLong);
+        }
+
+        let encoded = if label.is_ascii() {
+            // Existing xn-- labels must decode to be valid
+            if let Some(encoded) = strip_ace_prefix(&label) {
+                punycode_decode(encoded).ok_or(IdnaError::InvalidPunycode)?;
+            }
+            label
+        } else {
+            let encoded = punycode_encode(&label).ok_or(IdnaError::LabelTooLong)?;
+            format!("{}{}", ACE_PREFIX, encoded)
+        };
+
+        if encoded.len() > MAX_LABEL_LEN {
+            return Err(IdnaError::LabelTooLong);
+        }
+        labels.push(encoded);
+    }
+
+    let ascii = labels.join(".");
+    if ascii.len() > MAX_DOMAIN_LEN {
+        return Err(IdnaError::DomainTooLong);
+    }
+    Ok(ascii)
+}
+
+/// Converts a domain to its Unicode form, decoding `xn--` labels
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::idna::to_unicode;
+///
+/// assert_eq!(to_unicode("xn--exmple-cua.de").unwrap(), "exämple.de");
+/// assert_eq!(to_unicode("Example.com").unwrap(), "example.com");
+/// assert!(to_unicode("xn--a-.com").is_err());
+/// ```
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    let mut labels = Vec::new();
+    for label in split_labels(domain) {
+        let label = label.to_lowercase();
+        if label.is_empty() {
+            return Err(IdnaError::EmptyLabel);
+        }
+
+        match strip_ace_prefix(&label) {
+            Some(encoded) => {
+                labels.push(punycode_decode(encoded).ok_or(IdnaError::InvalidPunycode)?)
+            }
+            None => labels.push(label),
+        }
+    }
+    Ok(labels.join("."))
+}
+
+/// Returns `true` if the domain contains non-ASCII characters or `xn--` labels
+pub fn is_internationalized(domain: &str) -> bool {
+    !domain.is_ascii() || split_labels(domain).any(|label| strip_ace_prefix(label).is_some())
+}
+
+/// Splits on `.` and the ideographic full stops that IDNA treats as dots
+fn split_labels(domain: &str) -> impl Iterator<Item = &str> {
+    domain.split(['.', '\u{3002}', '\u{FF0E}', '\u{FF61}'])
+}
+
+fn strip_ace_prefix(label: &str) -> Option<&str> {
+    label
+        .get(..ACE_PREFIX.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(ACE_PREFIX))
+        .map(|_| &label[ACE_PREFIX.len()..])
+}
+
+/// Non-ASCII characters allowed in a label: letters, digits and combining marks
+fn is_unicode_label_char(c: char) -> bool {
+    !c.is_ascii() && (c.is_alphanumeric() || is_combining_mark(c))
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'
+        | '\u{0483}'..='\u{0489}'
+        | '\u{0591}'..='\u{05BD}'
+        | '\u{0610}'..='\u{061A}'
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0900}'..='\u{0903}'
+        | '\u{093A}'..='\u{094F}'
+        | '\u{0E31}'..='\u{0E3A}'
+        | '\u{0E47}'..='\u{0E4E}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{3099}'..='\u{309A}'
+        | '\u{FE20}'..='\u{FE2F}')
+}
+
+// Punycode parameters (RFC 3492 section 5)
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    match d {
+        0..=25 => (b'a' + d as u8) as char,
+        _ => (b'0' + (d - 26) as u8) as char,
+    }
+}
+
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encodes a label with Punycode, without the `xn--` prefix
+///
+/// Returns `None` if the label is too long for the encoder's arithmetic.
+fn punycode_encode(input: &str) -> Option<String> {
+    let input: Vec<u32> = input.chars().map(u32::from).collect();
+    let mut output: String = input
+        .iter()
+        .filter(|&&c| c < 0x80)
+        .map(|&c| c as u8 as char)
+        .collect();
+
+    let basic_len = output.len() as u32;
+    let mut handled = basic_len;
+    if basic_len > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    while (handled as usize) < input.len() {
+        let m = input.iter().copied().filter(|&c| c >= n).min().unwrap_or(n);
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for &c in &input {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(output)
+}
+
+/// Decodes a Punycode label without the `xn--` prefix
+fn punycode_decode(input: &str) -> Option<String> {
+    let (basic, extended) = match input.rfind('-') {
+        Some(index) => (&input[..index], &input[index + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() || extended.is_empty() {
+        return None;
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut chars = extended.chars().peekable();
+
+    while chars.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(chars.next()?)?;
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+
+    // Encoded labels must contain a non-ASCII character and be in canonical form
+    let decoded: String = output.into_iter().collect();
+    let canonical = punycode_encode(&decoded).is_some_and(|e| e == input.to_ascii_lowercase());
+    (!decoded.is_ascii() && canonical).then_some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punycode_rfc_samples() {
+        // RFC 3492 section 7.1
+        let samples = [
+            ("他们为什么不说中文", "ihqwcrb4cv8a8dqg056pqjye"),
+            ("bücher", "bcher-kva"),
+            ("münchen", "mnchen-3ya"),
+            ("правда", "80aafi6cg"),
+        ];
+        for (unicode, encoded) in samples {
+            assert_eq!(punycode_encode(unicode).unwrap(), encoded);
+            assert_eq!(punycode_decode(encoded).unwrap(), unicode);
+        }
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(to_ascii("例子.广告").unwrap(), "xn--fsqu00a.xn--4rr70v");
+        assert_eq!(to_ascii("Bücher.Example").unwrap(), "xn--bcher-kva.example");
+        assert_eq!(to_ascii("例子。广告").unwrap(), "xn--fsqu00a.xn--4rr70v");
+        assert_eq!(to_ascii("xn--bcher-kva.de").unwrap(), "xn--bcher-kva.de");
+
+        assert_eq!(to_ascii("a..b"), Err(IdnaError::EmptyLabel));
+        assert_eq!(to_ascii("a_b.com"), Err(IdnaError::InvalidCharacter('_')));
+        assert_eq!(to_ascii("☃.com"), Err(IdnaError::InvalidCharacter('☃')));
+        assert_eq!(to_ascii("xn--.com"), Err(IdnaError::InvalidPunycode));
+        assert_eq!(to_ascii(&"aü".repeat(32)), Err(IdnaError::LabelTooLong));
+    }
+
+    #[test]
+    fn test_long_labels_do_not_overflow() {
+        let label = format!("{}\u{2A6D6}", "a".repeat(30000));
+        assert_eq!(to_ascii(&label), Err(IdnaError::LabelTooLong));
+        assert_eq!(punycode_encode(&label), None);
+        assert!(!crate::url::is_valid_url(&format!("http://{}.com/", label)));
+
+        let encoded = format!("xn--{}-{}", "a".repeat(30000), "z".repeat(8));
+        assert!(to_unicode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(to_unicode("xn--fsqu00a.xn--4rr70v").unwrap(), "例子.广告");
+        assert_eq!(to_unicode("XN--BCHER-KVA.de").unwrap(), "bücher.de");
+        assert_eq!(
+            to_unicode("xn--bcher-.com"),
+            Err(IdnaError::InvalidPunycode)
+        );
+    }
+
+    #[test]
+    fn test_is_internationalized() {
+        assert!(is_internationalized("exämple.de"));
+        assert!(is_internationalized("xn--exmple-cua.de"));
+        assert!(!is_internationalized("example.de"));
+    }
+}
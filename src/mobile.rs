@@ -3,6 +3,8 @@
 //! This module provides comprehensive mobile phone validation for different countries
 //! and locales. It supports over 150 country/locale combinations.
 
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::OnceLock;
@@ -350,6 +352,76 @@ pub fn get_supported_locales() -> Vec<&'static str> {
     locales
 }
 
+/// Configurable phone number validator implementing [`Validator`]
+///
+/// Fails with code `phone.invalid`, or `phone.unknown_locale` when a requested
+/// locale is not supported. Both carry a `locale` parameter when a single
+/// locale was requested.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::mobile::PhoneValidator;
+/// use validator_rs::validator::Validator;
+///
+/// let validator = PhoneValidator::new().locale("en-US").strict_mode(true);
+/// assert!(validator.validate("+14155552671", "phone").is_ok());
+/// assert!(validator.validate("4155552671", "phone").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PhoneValidator {
+    /// Locale(s) the phone number must match
+    pub locale: Locale,
+    /// Validation options
+    pub options: MobileOptions,
+}
+
+impl Default for PhoneValidator {
+    fn default() -> Self {
+        Self {
+            locale: Locale::Any,
+            options: MobileOptions::default(),
+        }
+    }
+}
+
+impl PhoneValidator {
+    /// Create a new PhoneValidator accepting any locale
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the locale(s) to validate against
+    pub fn locale(mut self, locale: impl Into<Locale>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Set whether the number must start with '+'
+    pub fn strict_mode(mut self, strict: bool) -> Self {
+        self.options.strict_mode = strict;
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for PhoneValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let options = Some(self.options.clone());
+        let error = match is_mobile_phone(value.as_ref(), self.locale.clone(), options) {
+            Ok(true) => return Ok(()),
+            Ok(false) => {
+                ValidationError::new(field, "Invalid phone number").with_code("phone.invalid")
+            }
+            Err(message) => ValidationError::new(field, message).with_code("phone.unknown_locale"),
+        };
+
+        match &self.locale {
+            Locale::Specific(locale) => Err(error.with_param("locale", locale.as_str())),
+            _ => Err(error),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -749,5 +821,25 @@ mod tests {
         assert!(is_mobile_phone("6944848966", locale.clone(), None).unwrap());
         assert!(!is_mobile_phone("6924567890", locale, None).unwrap());
     }
+
+    #[test]
+    fn test_phone_validator() {
+        assert!(PhoneValidator::new().validate("+447911123456", "phone").is_ok());
+        assert!(PhoneValidator::new().validate("123", "phone").is_err());
+
+        let validator = PhoneValidator::new().locale("en-GB");
+        assert!(validator.validate("07911123456", "phone").is_ok());
+
+        let error = validator.validate("+14155552671", "phone").unwrap_err();
+        assert_eq!(error.code, "phone.invalid");
+        assert_eq!(error.param("locale").unwrap().to_string(), "en-GB");
+
+        let error = PhoneValidator::new()
+            .locale("xx-XX")
+            .validate("+14155552671", "phone")
+            .unwrap_err();
+        assert_eq!(error.code, "phone.unknown_locale");
+        assert!(error.message.contains("Invalid locale"));
+    }
 }
 
@@ -1,5 +1,9 @@
 //! Numeric validation functions
 
+use crate::validator::Validator;
+use crate::{ParamValue, ValidationError, ValidationResult};
+use std::fmt::Display;
+
 /// Validates if a number is within a specified range (inclusive)
 ///
 /// # Examples
@@ -70,6 +74,104 @@ pub fn is_close_to(value: f64, target: f64, tolerance: f64) -> bool {
     (value - target).abs() <= tolerance
 }
 
+/// Configurable numeric range validator implementing [`Validator`]
+///
+/// Fails with code `range.too_small` or `range.too_large` when only one bound
+/// is set and `range.out_of_range` when both are, with the configured `min`
+/// and `max` and the `actual` value as parameters. Works for any
+/// `PartialOrd + Display` type; parameters are numeric when the value
+/// displays as a number and strings otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::numeric::RangeValidator;
+/// use validator_rs::validator::Validator;
+///
+/// let validator = RangeValidator::new().min(18).max(120);
+/// assert!(validator.validate(&25, "age").is_ok());
+/// assert!(validator.validate(&17, "age").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeValidator<T> {
+    /// Minimum value (inclusive)
+    pub min: Option<T>,
+    /// Maximum value (inclusive)
+    pub max: Option<T>,
+}
+
+impl<T> Default for RangeValidator<T> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl<T> RangeValidator<T> {
+    /// Create a new RangeValidator without bounds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum value
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+/// Converts a bound to a parameter, typed as a number when its display form
+/// parses as one
+fn display_param(value: &impl Display) -> ParamValue {
+    let text = value.to_string();
+    if let Ok(v) = text.parse::<i64>() {
+        ParamValue::Int(v)
+    } else if let Ok(v) = text.parse::<u64>() {
+        ParamValue::UInt(v)
+    } else if let Ok(v) = text.parse::<f64>() {
+        ParamValue::Float(v)
+    } else {
+        ParamValue::Str(text)
+    }
+}
+
+impl<T: PartialOrd + Display> Validator<T> for RangeValidator<T> {
+    fn validate(&self, value: &T, field: &str) -> ValidationResult {
+        let code = match (&self.min, &self.max) {
+            (Some(min), Some(max)) if value < min || value > max => "range.out_of_range",
+            (Some(min), None) if value < min => "range.too_small",
+            (None, Some(max)) if value > max => "range.too_large",
+            _ => return Ok(()),
+        };
+
+        let message = match (&self.min, &self.max) {
+            (Some(min), Some(max)) => format!("Must be between {} and {}", min, max),
+            (Some(min), None) => format!("Must be at least {}", min),
+            (None, Some(max)) => format!("Must be at most {}", max),
+            (None, None) => unreachable!("a bound must be set to fail"),
+        };
+
+        let mut error = ValidationError::new(field, message)
+            .with_code(code)
+            .with_param("actual", display_param(value));
+        if let Some(min) = &self.min {
+            error = error.with_param("min", display_param(min));
+        }
+        if let Some(max) = &self.max {
+            error = error.with_param("max", display_param(max));
+        }
+        Err(error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,5 +234,58 @@ mod tests {
         assert!(is_close_to(1.0, 1.0, 0.0));
         assert!(!is_close_to(1.0, 1.1, 0.05));
     }
+
+    #[test]
+    fn test_range_validator() {
+        let validator = RangeValidator::new().min(1).max(10);
+        assert!(validator.validate(&1, "quantity").is_ok());
+        assert!(validator.validate(&10, "quantity").is_ok());
+
+        let error = validator.validate(&11, "quantity").unwrap_err();
+        assert_eq!(error.code, "range.out_of_range");
+        assert_eq!(error.message, "Must be between 1 and 10");
+        assert_eq!(error.param("min"), Some(&ParamValue::Int(1)));
+        assert_eq!(error.param("max"), Some(&ParamValue::Int(10)));
+        assert_eq!(error.param("actual"), Some(&ParamValue::Int(11)));
+
+        let error = validator.validate(&0, "quantity").unwrap_err();
+        assert_eq!(error.code, "range.out_of_range");
+
+        let error = RangeValidator::new().max(10).validate(&11, "quantity");
+        assert_eq!(error.unwrap_err().code, "range.too_large");
+        let error = RangeValidator::new().min(1).validate(&0, "quantity");
+        assert_eq!(error.unwrap_err().code, "range.too_small");
+
+        assert!(RangeValidator::new().min(0.5).validate(&0.1, "ratio").is_err());
+        assert!(RangeValidator::new().max(0.5).validate(&0.1, "ratio").is_ok());
+    }
+
+    #[test]
+    fn test_range_validator_display_type() {
+        #[derive(PartialEq, PartialOrd)]
+        struct Version(u32, u32, u32);
+
+        impl Display for Version {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}.{}.{}", self.0, self.1, self.2)
+            }
+        }
+
+        let validator = RangeValidator::new().min(Version(1, 2, 0));
+        assert!(validator.validate(&Version(1, 10, 0), "version").is_ok());
+        let error = validator
+            .validate(&Version(0, 9, 5), "version")
+            .unwrap_err();
+        assert_eq!(error.code, "range.too_small");
+        assert_eq!(error.message, "Must be at least 1.2.0");
+        assert_eq!(
+            error.param("min"),
+            Some(&ParamValue::Str("1.2.0".to_string()))
+        );
+        assert_eq!(
+            error.param("actual"),
+            Some(&ParamValue::Str("0.9.5".to_string()))
+        );
+    }
 }
 
@@ -1,5 +1,8 @@
 //! String validation functions
 
+use crate::validator::Validator;
+use crate::{ValidationError, ValidationResult};
+
 /// Validates if a string contains only alphanumeric characters
 ///
 /// # Examples
@@ -78,9 +81,83 @@ pub fn is_lowercase(s: &str) -> bool {
     !s.is_empty() && s.chars().filter(|c| c.is_alphabetic()).all(|c| c.is_lowercase())
 }
 
+/// Configurable string length validator implementing [`Validator`]
+///
+/// Fails with code `length.too_short` (with `min` and `actual` parameters) or
+/// `length.too_long` (with `max` and `actual` parameters).
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::string::LengthValidator;
+/// use validator_rs::validator::Validator;
+///
+/// let validator = LengthValidator::new().min(8).max(128);
+/// assert!(validator.validate("correct horse", "password").is_ok());
+/// assert!(validator.validate("short", "password").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LengthValidator {
+    /// Minimum length (inclusive)
+    pub min: Option<usize>,
+    /// Maximum length (inclusive)
+    pub max: Option<usize>,
+}
+
+impl LengthValidator {
+    /// Create a new LengthValidator without bounds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum length
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum length
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Validator<S> for LengthValidator {
+    fn validate(&self, value: &S, field: &str) -> ValidationResult {
+        let value = value.as_ref();
+        if let Some(min) = self.min {
+            if !has_min_length(value, min) {
+                return Err(ValidationError::new(
+                    field,
+                    format!("Must be at least {} characters long", min),
+                )
+                .with_code("length.too_short")
+                .with_param("min", min)
+                .with_param("actual", value.len()));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if !has_max_length(value, max) {
+                return Err(ValidationError::new(
+                    field,
+                    format!("Must not exceed {} characters", max),
+                )
+                .with_code("length.too_long")
+                .with_param("max", max)
+                .with_param("actual", value.len()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ParamValue;
 
     #[test]
     fn test_alphanumeric() {
@@ -136,5 +213,22 @@ mod tests {
         assert!(is_lowercase("hello123"));
         assert!(!is_lowercase("Hello"));
     }
+
+    #[test]
+    fn test_length_validator() {
+        let validator = LengthValidator::new().min(3).max(5);
+        assert!(validator.validate("hello", "name").is_ok());
+        assert!(validator.validate(&"hey".to_string(), "name").is_ok());
+
+        let error = validator.validate("hi", "name").unwrap_err();
+        assert_eq!(error.code, "length.too_short");
+        assert_eq!(error.message, "Must be at least 3 characters long");
+        assert_eq!(error.param("min"), Some(&ParamValue::UInt(3)));
+        assert_eq!(error.param("actual"), Some(&ParamValue::UInt(2)));
+
+        let error = validator.validate("hello world", "name").unwrap_err();
+        assert_eq!(error.code, "length.too_long");
+        assert_eq!(error.param("max"), Some(&ParamValue::UInt(5)));
+    }
 }
 
@@ -0,0 +1,477 @@
+//! Validated newtypes with serde support
+//!
+//! Each type in this module can only be constructed from a value that passes
+//! the corresponding module validator. Their `Deserialize` implementations run
+//! that validation, so invalid input is rejected while deserializing instead
+//! of relying on a separate validation step, and their `Serialize`
+//! implementations emit the normalized form.
+//!
+//! Requires the `serde` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Deserialize;
+//! use validator_rs::types::{Email, PhoneNumber};
+//!
+//! #[derive(Deserialize)]
+//! struct Signup {
+//!     email: Email,
+//!     phone: PhoneNumber,
+//! }
+//!
+//! let signup: Signup =
+//!     serde_json::from_str(r#"{"email": "User@Example.COM", "phone": "+1 (415) 555-2671"}"#).unwrap();
+//! assert_eq!(signup.email.as_str(), "User@example.com");
+//! assert_eq!(signup.phone.as_str(), "+14155552671");
+//!
+//! let result: Result<Signup, _> =
+//!     serde_json::from_str(r#"{"email": "invalid", "phone": "+14155552671"}"#);
+//! assert!(result.is_err());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::credit_card::{get_card_type, CardType, CreditCardValidator};
+use crate::currency::{CurrencyOptions, CurrencyValidator};
+use crate::date::{DateFormat, DateValidator};
+use crate::email::EmailValidator;
+use crate::mobile::{Locale, PhoneValidator};
+use crate::url::UrlValidator;
+use crate::validator::Validator;
+use crate::ValidationError;
+
+/// Implements the conversions and serde support shared by every newtype
+///
+/// The type must provide `fn parse(value: &str) -> Result<Self, ValidationError>`.
+macro_rules! validated_newtype {
+    ($name:ident, $expected:expr) => {
+        impl $name {
+            /// Returns the normalized value
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the normalized value as an owned `String`
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ValidationError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::parse(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::parse(&value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Self::parse(&value).map_err(|error| {
+                    serde::de::Error::custom(format!("invalid {}: {}", $expected, error.message))
+                })
+            }
+        }
+    };
+}
+
+/// An email address validated by [`EmailValidator`]
+///
+/// Normalized by trimming surrounding whitespace and lowercasing the domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Email(String);
+
+impl Email {
+    /// Validates and normalizes an email address
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        EmailValidator::new().validate(value, "email")?;
+
+        let (local, domain) = value.rsplit_once('@').unwrap_or((value, ""));
+        Ok(Email(format!("{}@{}", local, domain.to_lowercase())))
+    }
+
+    /// Returns the part before the `@`
+    pub fn local_part(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(local, _)| local)
+    }
+
+    /// Returns the part after the `@`
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+}
+
+validated_newtype!(Email, "email address");
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An HTTP or HTTPS URL validated by [`UrlValidator`]
+///
+/// Normalized by trimming surrounding whitespace and lowercasing the scheme and host.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HttpUrl(String);
+
+impl HttpUrl {
+    /// Validates and normalizes an HTTP or HTTPS URL
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        let (scheme, rest) = value.split_once("://").unwrap_or(("", value));
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, tail) = rest.split_at(authority_end);
+        let authority = match authority.rsplit_once('@') {
+            Some((userinfo, host)) => format!("{}@{}", userinfo, host.to_ascii_lowercase()),
+            None => authority.to_ascii_lowercase(),
+        };
+
+        let normalized = format!("{}://{}{}", scheme.to_ascii_lowercase(), authority, tail);
+        UrlValidator::new().validate(&normalized, "url")?;
+        Ok(HttpUrl(normalized))
+    }
+
+    /// Returns `true` if the URL uses HTTPS
+    pub fn is_https(&self) -> bool {
+        self.0.starts_with("https://")
+    }
+}
+
+validated_newtype!(HttpUrl, "URL");
+
+impl fmt::Display for HttpUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A mobile phone number validated by [`PhoneValidator`] against any locale
+///
+/// Normalized by removing spaces, dashes, dots and parentheses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    /// Validates and normalizes a phone number for any locale
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        Self::parse_with_locale(value, Locale::Any)
+    }
+
+    /// Validates and normalizes a phone number for specific locale(s)
+    pub fn parse_with_locale(
+        value: &str,
+        locale: impl Into<Locale>,
+    ) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        PhoneValidator::new()
+            .locale(locale)
+            .validate(value, "phone")?;
+
+        Ok(PhoneNumber(
+            value
+                .chars()
+                .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+                .collect(),
+        ))
+    }
+}
+
+validated_newtype!(PhoneNumber, "phone number");
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A credit card number validated by [`CreditCardValidator`]
+///
+/// Normalized to digits only. `Debug` and `Display` only reveal the last four
+/// digits, and deserialization errors never include the number.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CardNumber(String);
+
+impl CardNumber {
+    /// Validates and normalizes a credit card number
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        CreditCardValidator::new().validate(value, "card_number")?;
+
+        Ok(CardNumber(
+            value.chars().filter(|c| c.is_ascii_digit()).collect(),
+        ))
+    }
+
+    /// Returns the detected card type
+    pub fn card_type(&self) -> CardType {
+        get_card_type(&self.0)
+    }
+
+    /// Returns the last four digits
+    pub fn last_four(&self) -> &str {
+        &self.0[self.0.len() - 4..]
+    }
+}
+
+validated_newtype!(CardNumber, "card number");
+
+impl fmt::Debug for CardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CardNumber(****{})", self.last_four())
+    }
+}
+
+impl fmt::Display for CardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "****{}", self.last_four())
+    }
+}
+
+/// An ISO 8601 date (YYYY-MM-DD) validated by [`DateValidator`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IsoDate(String);
+
+impl IsoDate {
+    /// Validates an ISO 8601 date
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        DateValidator::new().validate(value, "date")?;
+        Ok(IsoDate(value.to_string()))
+    }
+
+    /// Returns the year
+    pub fn year(&self) -> i32 {
+        self.0[0..4].parse().expect("validated date")
+    }
+
+    /// Returns the month (1-12)
+    pub fn month(&self) -> u32 {
+        self.0[5..7].parse().expect("validated date")
+    }
+
+    /// Returns the day of the month (1-31)
+    pub fn day(&self) -> u32 {
+        self.0[8..10].parse().expect("validated date")
+    }
+}
+
+validated_newtype!(IsoDate, "date");
+
+impl fmt::Display for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An ISO 8601 datetime validated by [`DateValidator`]
+///
+/// Normalized by uppercasing the `T` separator and `Z` suffix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IsoDateTime(String);
+
+impl IsoDateTime {
+    /// Validates and normalizes an ISO 8601 datetime
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim().to_ascii_uppercase();
+        DateValidator::new()
+            .format(DateFormat::DateTime)
+            .validate(&value, "datetime")?;
+        // The datetime pattern's `\d` also matches non-ASCII digits
+        if !value.is_ascii() {
+            return Err(
+                ValidationError::new("datetime", "Invalid datetime").with_code("datetime.invalid")
+            );
+        }
+        Ok(IsoDateTime(value))
+    }
+
+    /// Returns the date part
+    pub fn date(&self) -> &str {
+        &self.0[..10]
+    }
+}
+
+validated_newtype!(IsoDateTime, "datetime");
+
+impl fmt::Display for IsoDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A currency amount validated by [`CurrencyValidator`]
+///
+/// Deserialization uses the default (US) [`CurrencyOptions`]; use
+/// [`CurrencyAmount::parse_with_options`] for other formats. The value is
+/// kept as written, without surrounding whitespace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurrencyAmount(String);
+
+impl CurrencyAmount {
+    /// Validates a currency amount using the default options
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        Self::parse_with_options(value, CurrencyOptions::default())
+    }
+
+    /// Validates a currency amount using custom options
+    pub fn parse_with_options(
+        value: &str,
+        options: CurrencyOptions,
+    ) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        CurrencyValidator::new(options).validate(value, "amount")?;
+        Ok(CurrencyAmount(value.to_string()))
+    }
+}
+
+validated_newtype!(CurrencyAmount, "currency amount");
+
+impl fmt::Display for CurrencyAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_json<T: for<'de> Deserialize<'de>>(json: &str) -> Result<T, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_email() {
+        let email: Email = from_json(r#""  John.Doe@Example.COM ""#).unwrap();
+        assert_eq!(email.as_str(), "John.Doe@example.com");
+        assert_eq!(email.local_part(), "John.Doe");
+        assert_eq!(email.domain(), "example.com");
+        assert_eq!(
+            serde_json::to_string(&email).unwrap(),
+            r#""John.Doe@example.com""#
+        );
+
+        let error = from_json::<Email>(r#""invalid""#).unwrap_err();
+        assert!(error.contains("invalid email address: Invalid email format"));
+    }
+
+    #[test]
+    fn test_http_url() {
+        let url: HttpUrl = from_json(r#""HTTPS://User@Example.COM/Path?Q=1""#).unwrap();
+        assert_eq!(url.as_str(), "https://User@example.com/Path?Q=1");
+        assert!(url.is_https());
+
+        assert!(from_json::<HttpUrl>(r#""ftp://example.com""#).is_err());
+    }
+
+    #[test]
+    fn test_phone_number() {
+        let phone: PhoneNumber = from_json(r#""+1 (415) 555-2671""#).unwrap();
+        assert_eq!(phone.as_str(), "+14155552671");
+        assert_eq!(serde_json::to_string(&phone).unwrap(), r#""+14155552671""#);
+
+        assert!(PhoneNumber::parse_with_locale("07911123456", "en-GB").is_ok());
+        assert!(from_json::<PhoneNumber>(r#""123""#)
+            .unwrap_err()
+            .contains("invalid phone number"));
+    }
+
+    #[test]
+    fn test_card_number() {
+        let card: CardNumber = from_json(r#""4532 0151 1283 0366""#).unwrap();
+        assert_eq!(card.as_str(), "4532015112830366");
+        assert_eq!(card.card_type(), CardType::Visa);
+        assert_eq!(format!("{:?}", card), "CardNumber(****0366)");
+        assert_eq!(card.to_string(), "****0366");
+
+        let error = from_json::<CardNumber>(r#""4532015112830367""#).unwrap_err();
+        assert!(error.contains("invalid card number"));
+        assert!(!error.contains("4532015112830367"));
+    }
+
+    #[test]
+    fn test_iso_date() {
+        let date: IsoDate = from_json(r#""2024-02-29""#).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert!(from_json::<IsoDate>(r#""2023-02-29""#).is_err());
+    }
+
+    #[test]
+    fn test_iso_datetime() {
+        let datetime: IsoDateTime = from_json(r#""2023-12-31t23:59:59z""#).unwrap();
+        assert_eq!(datetime.as_str(), "2023-12-31T23:59:59Z");
+        assert_eq!(datetime.date(), "2023-12-31");
+        assert!(from_json::<IsoDateTime>(r#""2023-12-31 23:59:59""#).is_err());
+        assert!(from_json::<IsoDateTime>(r#""2024-01-0०T00:00:00""#).is_err());
+        assert!(from_json::<IsoDateTime>(r#""२024-01-01T00:00:00Z""#).is_err());
+        assert!(IsoDateTime::parse("२024-01-01T00:00:00Z").is_err());
+    }
+
+    #[test]
+    fn test_currency_amount() {
+        let amount: CurrencyAmount = from_json(r#""$10,123.45""#).unwrap();
+        assert_eq!(amount.as_str(), "$10,123.45");
+        assert!(from_json::<CurrencyAmount>(r#""$ 32.50""#).is_err());
+
+        let euro = CurrencyOptions::new()
+            .symbol("€")
+            .thousands_separator('.')
+            .decimal_separator(',');
+        assert!(CurrencyAmount::parse_with_options("€1.234,56", euro).is_ok());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let email: Email = "user@example.com".parse().unwrap();
+        assert_eq!(String::from(email.clone()), "user@example.com");
+        assert_eq!(
+            Email::try_from("user@example.com".to_string()).unwrap(),
+            email
+        );
+        assert_eq!(
+            Email::try_from("invalid").unwrap_err().code,
+            "email.invalid"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Composable validators
+//!
+//! This module provides the [`Validator`] trait implemented by the configurable
+//! validator structs of every module (e.g. [`EmailValidator`](crate::email::EmailValidator),
+//! [`LengthValidator`](crate::string::LengthValidator)), together with combinators
+//! for building larger rules out of smaller ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use validator_rs::email::EmailValidator;
+//! use validator_rs::string::LengthValidator;
+//! use validator_rs::validator::{Validator, ValidatorExt};
+//!
+//! let rule = EmailValidator::new().and(LengthValidator::new().max(64));
+//!
+//! assert!(rule.validate("user@example.com", "email").is_ok());
+//! assert!(rule.validate("invalid.email", "email").is_err());
+//! ```
+
+use crate::{ValidationError, ValidationErrors, ValidationResult};
+
+/// A reusable validation rule for values of type `T`
+///
+/// The `field` argument names the value being validated and is used as the
+/// field of any returned [`ValidationError`].
+pub trait Validator<T: ?Sized> {
+    /// Validates `value`, returning an error describing the first failure
+    fn validate(&self, value: &T, field: &str) -> ValidationResult;
+
+    /// Returns `true` if `value` passes validation
+    fn is_valid(&self, value: &T) -> bool {
+        self.validate(value, "").is_ok()
+    }
+}
+
+/// Validation of a whole value, reporting every failing field
+///
+/// This is usually implemented with `#[derive(Validate)]` (requires the
+/// `derive` feature), which maps `#[validate(...)]` field attributes onto the
+/// validators of each module.
+///
+/// # Examples
+///
+/// ```
+/// use validator_rs::Validate;
+///
+/// #[derive(validator_rs_derive::Validate)]
+/// struct SignupForm {
+///     #[validate(email)]
+///     email: String,
+///     #[validate(length(min = 8, max = 128))]
+///     password: String,
+/// }
+///
+/// let form = SignupForm {
+///     email: "invalid".to_string(),
+///     password: "short".to_string(),
+/// };
+/// assert_eq!(form.validate().unwrap_err().len(), 2);
+/// ```
+pub trait Validate {
+    /// Validates every field, collecting all failures
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Combinators available on every validator
+///
+/// These live in a separate trait so that validators accepting several value
+/// types (such as both `str` and `String`) can be combined before the value
+/// type is known.
+pub trait ValidatorExt: Sized {
+    /// Requires both this validator and `other` to pass
+    fn and<V>(self, other: V) -> And<Self, V> {
+        And {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Requires either this validator or `other` to pass
+    fn or<V>(self, other: V) -> Or<Self, V> {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Inverts this validator, failing with `message` when it passes
+    fn not(self, message: impl Into<String>) -> Not<Self> {
+        Not {
+            inner: self,
+            message: message.into(),
+        }
+    }
+
+    /// Accepts `None` and validates the contained value otherwise
+    fn optional(self) -> Optional<Self> {
+        Optional { inner: self }
+    }
+
+    /// Validates every element of a slice or vector
+    fn each(self) -> Each<Self> {
+        Each { inner: self }
+    }
+}
+
+impl<V> ValidatorExt for V {}
+
+impl<T, F> Validator<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T, &str) -> ValidationResult,
+{
+    fn validate(&self, value: &T, field: &str) -> ValidationResult {
+        self(value, field)
+    }
+}
+
+/// Validator that requires two validators to pass, created by [`ValidatorExt::and`]
+#[derive(Debug, Clone)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T, A, B> Validator<T> for And<A, B>
+where
+    T: ?Sized,
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    fn validate(&self, value: &T, field: &str) -> ValidationResult {
+        self.first.validate(value, field)?;
+        self.second.validate(value, field)
+    }
+}
+
+/// Validator that requires either of two validators to pass, created by [`ValidatorExt::or`]
+///
+/// When both fail, the error of the second validator is returned.
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T, A, B> Validator<T> for Or<A, B>
+where
+    T: ?Sized,
+    A: Validator<T>,
+    B: Validator<T>,
+{
+    fn validate(&self, value: &T, field: &str) -> ValidationResult {
+        if self.first.validate(value, field).is_ok() {
+            return Ok(());
+        }
+        self.second.validate(value, field)
+    }
+}
+
+/// Validator that passes only when the inner validator fails, created by [`ValidatorExt::not`]
+///
+/// Fails with code `not` and the configured message.
+#[derive(Debug, Clone)]
+pub struct Not<V> {
+    inner: V,
+    message: String,
+}
+
+impl<T, V> Validator<T> for Not<V>
+where
+    T: ?Sized,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T, field: &str) -> ValidationResult {
+        match self.inner.validate(value, field) {
+            Ok(()) => Err(ValidationError::new(field, self.message.clone()).with_code("not")),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Validator for optional values, created by [`ValidatorExt::optional`]
+#[derive(Debug, Clone)]
+pub struct Optional<V> {
+    inner: V,
+}
+
+impl<T, V> Validator<Option<T>> for Optional<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, value: &Option<T>, field: &str) -> ValidationResult {
+        match value {
+            Some(v) => self.inner.validate(v, field),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validator for every element of a collection, created by [`ValidatorExt::each`]
+///
+/// Errors are reported against the indexed field name, e.g. `tags[2]`.
+#[derive(Debug, Clone)]
+pub struct Each<V> {
+    inner: V,
+}
+
+impl<T, V> Validator<[T]> for Each<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, value: &[T], field: &str) -> ValidationResult {
+        for (i, item) in value.iter().enumerate() {
+            self.inner.validate(item, &format!("{}[{}]", field, i))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, V> Validator<Vec<T>> for Each<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, value: &Vec<T>, field: &str) -> ValidationResult {
+        Validator::<[T]>::validate(self, value.as_slice(), field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::email::EmailValidator;
+    use crate::numeric::RangeValidator;
+    use crate::string::LengthValidator;
+
+    #[test]
+    fn test_and() {
+        let rule = EmailValidator::new().and(LengthValidator::new().max(20));
+        assert!(rule.validate("user@example.com", "email").is_ok());
+        assert!(rule.validate("invalid", "email").is_err());
+        assert!(rule
+            .validate("a.very.long.address@example.com", "email")
+            .is_err());
+    }
+
+    #[test]
+    fn test_or() {
+        let rule = RangeValidator::new().max(0).or(RangeValidator::new().min(10));
+        assert!(rule.validate(&-5, "n").is_ok());
+        assert!(rule.validate(&15, "n").is_ok());
+        assert!(rule.validate(&5, "n").is_err());
+    }
+
+    #[test]
+    fn test_not() {
+        let rule = EmailValidator::new().not("Must not be an email address");
+        assert!(rule.validate("username", "login").is_ok());
+
+        let error = rule.validate("user@example.com", "login").unwrap_err();
+        assert_eq!(error.field, "login");
+        assert_eq!(error.code, "not");
+        assert_eq!(error.message, "Must not be an email address");
+    }
+
+    #[test]
+    fn test_optional() {
+        let rule = EmailValidator::new().optional();
+        assert!(rule.validate(&None::<String>, "email").is_ok());
+        assert!(rule
+            .validate(&Some("user@example.com".to_string()), "email")
+            .is_ok());
+        assert!(rule.validate(&Some("invalid".to_string()), "email").is_err());
+    }
+
+    #[test]
+    fn test_each() {
+        let rule = EmailValidator::new().each();
+        assert!(rule
+            .validate(&vec!["a@example.com", "b@example.com"], "emails")
+            .is_ok());
+
+        let error = rule
+            .validate(&vec!["a@example.com", "invalid"], "emails")
+            .unwrap_err();
+        assert_eq!(error.field, "emails[1]");
+    }
+
+    #[test]
+    fn test_closure() {
+        let has_digit = |value: &str, field: &str| {
+            if value.chars().any(|c| c.is_ascii_digit()) {
+                Ok(())
+            } else {
+                Err(ValidationError::new(field, "Must contain a digit"))
+            }
+        };
+        let rule = LengthValidator::new().min(8).and(has_digit);
+        assert!(rule.validate("password1", "password").is_ok());
+        assert!(rule.validate("password", "password").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use validator_rs::{Validate, ValidationErrors};
+
+#[derive(validator_rs_derive::Validate)]
+struct UserRegistration {
+    #[validate(email)]
+    email: String,
+    #[validate(phone(locale = "en-US", strict))]
+    phone: String,
+    #[validate(url(https))]
+    website: String,
+    #[validate(credit_card)]
+    credit_card: String,
+    #[validate(length(min = 8, max = 128))]
+    password: String,
+    #[validate(range(min = 18, max = 120))]
+    age: u8,
+    #[validate(currency(symbol = "€", thousands_separator = '.', decimal_separator = ','))]
+    balance: String,
+    #[validate(email, length(max = 32))]
+    backup_email: Option<String>,
+    #[allow(dead_code)]
+    nickname: String,
+}
+
+fn valid_user() -> UserRegistration {
+    UserRegistration {
+        email: "user@example.com".to_string(),
+        phone: "+14155552671".to_string(),
+        website: "https://example.com".to_string(),
+        credit_card: "4532015112830366".to_string(),
+        password: "correct horse".to_string(),
+        age: 30,
+        balance: "€1.234,56".to_string(),
+        backup_email: None,
+        nickname: String::new(),
+    }
+}
+
+fn failing_fields(errors: &ValidationErrors) -> Vec<&str> {
+    errors.iter().map(|e| e.field.as_str()).collect()
+}
+
+#[test]
+fn test_valid_struct() {
+    assert!(valid_user().validate().is_ok());
+
+    let user = UserRegistration {
+        backup_email: Some("backup@example.com".to_string()),
+        ..valid_user()
+    };
+    assert!(user.validate().is_ok());
+}
+
+#[test]
+fn test_collects_every_failing_field() {
+    let user = UserRegistration {
+        email: "invalid-email".to_string(),
+        phone: "4155552671".to_string(),
+        website: "http://example.com".to_string(),
+        credit_card: "1234567890123456".to_string(),
+        password: "short".to_string(),
+        age: 17,
+        balance: "$1,234.56".to_string(),
+        backup_email: Some("not-an-email".to_string()),
+        nickname: String::new(),
+    };
+
+    let errors = user.validate().unwrap_err();
+    assert_eq!(
+        failing_fields(&errors),
+        vec![
+            "email",
+            "phone",
+            "website",
+            "credit_card",
+            "password",
+            "age",
+            "balance",
+            "backup_email",
+        ]
+    );
+}
+
+#[test]
+fn test_multiple_rules_on_one_field() {
+    let user = UserRegistration {
+        backup_email: Some("a.very.long.backup.address@example.com".to_string()),
+        ..valid_user()
+    };
+
+    let errors = user.validate().unwrap_err();
+    assert_eq!(failing_fields(&errors), vec!["backup_email"]);
+
+    let error = errors.iter().next().unwrap();
+    assert_eq!(error.code, "length.too_long");
+    assert_eq!(error.message, "Must not exceed 32 characters");
+}
+
+#[derive(validator_rs_derive::Validate)]
+struct Quantity(#[validate(range(min = 1, max = 10))] i32);
+
+#[test]
+fn test_tuple_struct() {
+    assert!(Quantity(5).validate().is_ok());
+
+    let errors = Quantity(0).validate().unwrap_err();
+    assert_eq!(failing_fields(&errors), vec!["0"]);
+}
+
+#[derive(validator_rs_derive::Validate)]
+struct Empty {}
+
+#[test]
+fn test_struct_without_rules() {
+    assert!(Empty {}.validate().is_ok());
+}
+
+#[derive(validator_rs_derive::Validate)]
+struct Address {
+    #[validate(length(min = 1, max = 16))]
+    city: String,
+    #[validate(length(min = 4, max = 10))]
+    zip: String,
+}
+
+#[derive(validator_rs_derive::Validate)]
+struct Item {
+    #[validate(currency)]
+    price: String,
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+#[derive(validator_rs_derive::Validate)]
+struct Order {
+    #[validate(email)]
+    email: String,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    items: Vec<Item>,
+    #[validate(nested)]
+    gift_wrap: Option<Item>,
+}
+
+#[test]
+fn test_nested_paths() {
+    let order = Order {
+        email: "invalid".to_string(),
+        address: Address {
+            city: "Berlin".to_string(),
+            zip: "1".to_string(),
+        },
+        items: vec![
+            Item {
+                price: "$10.00".to_string(),
+                quantity: 1,
+            },
+            Item {
+                price: "ten".to_string(),
+                quantity: 0,
+            },
+        ],
+        gift_wrap: Some(Item {
+            price: "$1.00".to_string(),
+            quantity: 0,
+        }),
+    };
+
+    let errors = order.validate().unwrap_err();
+    assert_eq!(
+        failing_fields(&errors),
+        vec![
+            "email",
+            "address.zip",
+            "items[1].price",
+            "items[1].quantity",
+            "gift_wrap.quantity",
+        ]
+    );
+    assert!(errors.contains("items[1].price"));
+}
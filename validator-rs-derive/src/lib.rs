@@ -0,0 +1,277 @@
+//! # Validator-rs derive
+//!
+//! Provides `#[derive(Validate)]` for [validator-rs](https://docs.rs/validator-rs).
+//! Each `#[validate(...)]` field attribute maps onto the validator of the
+//! corresponding module, and the generated `validate(&self)` collects every
+//! failing field into a `ValidationErrors`.
+//!
+//! Supported rules:
+//!
+//! - `email`
+//! - `url`, `url(https)`
+//! - `phone`, `phone(locale = "en-US", strict)`
+//! - `length(min = 8, max = 128)`
+//! - `range(min = 1, max = 10)`
+//! - `currency`, `currency(symbol = "€", thousands_separator = '.')` (any
+//!   `CurrencyOptions` builder method; flags without a value are set to `true`)
+//! - `credit_card`
+//! - `nested` for fields implementing `Validate` (including `Vec`, `Option`
+//!   and maps of them); their errors are reported below the field's path,
+//!   e.g. `address.zip` or `items[0].price`
+//!
+//! Fields of type `Option<T>` are only validated when they are `Some`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Field, Ident, LitStr, Type};
+
+/// Derives `validator_rs::Validate` from `#[validate(...)]` field attributes
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A single validation rule parsed from a `#[validate(...)]` attribute
+enum Rule {
+    Email,
+    Url { https: bool },
+    Phone { locale: Option<LitStr>, strict: bool },
+    Length { min: Option<Expr>, max: Option<Expr> },
+    Range { min: Option<Expr>, max: Option<Expr> },
+    Currency { options: Vec<(Ident, Expr)> },
+    CreditCard,
+    Nested,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Validate can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut checks = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let rules = parse_rules(field)?;
+        if rules.is_empty() {
+            continue;
+        }
+
+        let (member, name) = match &field.ident {
+            Some(ident) => (quote!(#ident), ident.to_string()),
+            None => {
+                let index = syn::Index::from(index);
+                (quote!(#index), index.index.to_string())
+            }
+        };
+
+        let validations = rules.iter().map(|rule| match rule {
+            Rule::Nested => quote! {
+                errors.merge_result(#name, ::validator_rs::Validate::validate(value));
+            },
+            _ => {
+                let validator = validator_tokens(rule);
+                quote! {
+                    if let Err(error) = ::validator_rs::Validator::validate(&#validator, value, #name) {
+                        errors.add(error);
+                    }
+                }
+            }
+        });
+
+        if is_option(&field.ty) {
+            checks.push(quote! {
+                if let Some(value) = &self.#member {
+                    #(#validations)*
+                }
+            });
+        } else {
+            checks.push(quote! {
+                {
+                    let value = &self.#member;
+                    #(#validations)*
+                }
+            });
+        }
+    }
+
+    // Avoid an unused-mut warning for structs without validated fields
+    let errors_binding = if checks.is_empty() {
+        quote!(errors)
+    } else {
+        quote!(mut errors)
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::validator_rs::Validate for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::std::result::Result<(), ::validator_rs::ValidationErrors> {
+                let #errors_binding = ::validator_rs::ValidationErrors::new();
+                #(#checks)*
+                errors.into_result()
+            }
+        }
+    })
+}
+
+fn parse_rules(field: &Field) -> syn::Result<Vec<Rule>> {
+    let mut rules = Vec::new();
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            rules.push(parse_rule(&meta)?);
+            Ok(())
+        })?;
+    }
+    Ok(rules)
+}
+
+fn parse_rule(meta: &ParseNestedMeta) -> syn::Result<Rule> {
+    let name = meta
+        .path
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+
+    match name.as_str() {
+        "email" => Ok(Rule::Email),
+        "credit_card" => Ok(Rule::CreditCard),
+        "nested" => Ok(Rule::Nested),
+        "url" => {
+            let mut https = false;
+            parse_args(meta, |arg| {
+                if arg.path.is_ident("https") {
+                    https = true;
+                    Ok(())
+                } else {
+                    Err(arg.error("unknown url option, expected `https`"))
+                }
+            })?;
+            Ok(Rule::Url { https })
+        }
+        "phone" => {
+            let mut locale = None;
+            let mut strict = false;
+            parse_args(meta, |arg| {
+                if arg.path.is_ident("locale") {
+                    locale = Some(arg.value()?.parse()?);
+                    Ok(())
+                } else if arg.path.is_ident("strict") {
+                    strict = true;
+                    Ok(())
+                } else {
+                    Err(arg.error("unknown phone option, expected `locale` or `strict`"))
+                }
+            })?;
+            Ok(Rule::Phone { locale, strict })
+        }
+        "length" | "range" => {
+            let mut min = None;
+            let mut max = None;
+            parse_args(meta, |arg| {
+                if arg.path.is_ident("min") {
+                    min = Some(arg.value()?.parse()?);
+                    Ok(())
+                } else if arg.path.is_ident("max") {
+                    max = Some(arg.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(arg.error(format!("unknown {} option, expected `min` or `max`", name)))
+                }
+            })?;
+            if name == "length" {
+                Ok(Rule::Length { min, max })
+            } else {
+                Ok(Rule::Range { min, max })
+            }
+        }
+        "currency" => {
+            let mut options = Vec::new();
+            parse_args(meta, |arg| {
+                let ident = arg
+                    .path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| arg.error("expected a currency option name"))?;
+                let value = if arg.input.peek(syn::Token![=]) {
+                    arg.value()?.parse()?
+                } else {
+                    syn::parse_quote!(true)
+                };
+                options.push((ident, value));
+                Ok(())
+            })?;
+            Ok(Rule::Currency { options })
+        }
+        _ => Err(meta.error(
+            "unknown validation rule, expected one of `email`, `url`, `phone`, \
+             `length`, `range`, `currency`, `credit_card` or `nested`",
+        )),
+    }
+}
+
+/// Parses the optional parenthesized arguments of a rule
+fn parse_args(
+    meta: &ParseNestedMeta,
+    logic: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(logic)
+    } else {
+        Ok(())
+    }
+}
+
+fn validator_tokens(rule: &Rule) -> TokenStream2 {
+    match rule {
+        Rule::Email => quote!(::validator_rs::email::EmailValidator::new()),
+        Rule::CreditCard => quote!(::validator_rs::credit_card::CreditCardValidator::new()),
+        Rule::Url { https } => {
+            quote!(::validator_rs::url::UrlValidator::new().https_only(#https))
+        }
+        Rule::Phone { locale, strict } => {
+            let locale = locale.as_ref().map(|l| quote!(.locale(#l)));
+            quote!(::validator_rs::mobile::PhoneValidator::new() #locale .strict_mode(#strict))
+        }
+        Rule::Length { min, max } => {
+            let min = min.as_ref().map(|v| quote!(.min(#v)));
+            let max = max.as_ref().map(|v| quote!(.max(#v)));
+            quote!(::validator_rs::string::LengthValidator::new() #min #max)
+        }
+        Rule::Range { min, max } => {
+            let min = min.as_ref().map(|v| quote!(.min(#v)));
+            let max = max.as_ref().map(|v| quote!(.max(#v)));
+            quote!(::validator_rs::numeric::RangeValidator::new() #min #max)
+        }
+        Rule::Currency { options } => {
+            let setters = options.iter().map(|(name, value)| quote!(.#name(#value)));
+            quote! {
+                ::validator_rs::currency::CurrencyValidator::new(
+                    ::validator_rs::currency::CurrencyOptions::new() #(#setters)*
+                )
+            }
+        }
+        Rule::Nested => unreachable!("nested rules do not use a validator"),
+    }
+}
+
+/// Returns `true` if the type is syntactically an `Option<...>`
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}